@@ -0,0 +1,72 @@
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rust_decimal::Decimal;
+use scinote::SciNote;
+
+/// Amount of values summed or multiplied on each iteration
+const VALUES: usize = 10_000;
+
+/// Values with coefficients of different lengths and exponents, so every operation normalizes
+fn values() -> Vec<SciNote> {
+    (0..VALUES)
+        .map(|index| {
+            let coefficient = Decimal::new(index as i64 * 7919 + 1, (index % 12) as u32);
+            SciNote::build().coefficient(coefficient).exponent((index % 40) as i16 - 20).finish().unwrap()
+        })
+        .collect()
+}
+
+fn bulk_sum(c: &mut Criterion) {
+    let values = values();
+    let mut group = c.benchmark_group("bulk");
+    group.throughput(Throughput::Elements(VALUES as u64));
+
+    group.bench_function("sum", |b| {
+        b.iter(|| {
+            black_box(&values)
+                .iter()
+                .fold(SciNote::zero(), |sum, value| sum + value)
+        })
+    });
+
+    group.bench_function("product", |b| {
+        let factors = values.iter().map(|value| value.normalize().unwrap()).collect::<Vec<SciNote>>();
+        b.iter(|| {
+            black_box(&factors)
+                .iter()
+                .fold(SciNote::default(), |product, factor| product.saturating_mul(factor))
+        })
+    });
+
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let coefficients = (0..VALUES)
+        .map(|index| Decimal::new(index as i64 * 104729 + 1, (index % 28) as u32))
+        .collect::<Vec<Decimal>>();
+    let mut group = c.benchmark_group("normalize");
+    group.throughput(Throughput::Elements(VALUES as u64));
+
+    group.bench_function("builder coefficient", |b| {
+        b.iter(|| {
+            for coefficient in black_box(&coefficients) {
+                black_box(SciNote::build().coefficient(*coefficient).finish().unwrap());
+            }
+        })
+    });
+
+    group.bench_function("parse", |b| {
+        let inputs = coefficients.iter().map(|coefficient| format!("{}x10^12", coefficient)).collect::<Vec<String>>();
+        b.iter(|| {
+            for input in black_box(&inputs) {
+                black_box(SciNote::parse_from_str(input).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bulk_sum, normalize);
+criterion_main!(benches);
@@ -1,5 +1,14 @@
 mod modules;
 
+pub use crate::modules::scientific_notation::big::BigSciNote;
+pub use crate::modules::scientific_notation::builder::SciNoteBuilder;
+pub use crate::modules::scientific_notation::context::SciContext;
+pub use crate::modules::scientific_notation::context::Signal;
+pub use crate::modules::scientific_notation::error::ParsePart;
 pub use crate::modules::scientific_notation::error::SciError;
+pub use crate::modules::scientific_notation::error::SciParseError;
 pub use crate::modules::scientific_notation::error::SciResult;
+pub use crate::modules::scientific_notation::notation::Notation;
+pub use crate::modules::scientific_notation::rounding::RoundingMode;
 pub use crate::modules::scientific_notation::sci_not::SciNote;
+pub use crate::modules::scientific_notation::wide::WideSciNote;
@@ -0,0 +1,552 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, LowerExp, UpperExp};
+use std::hash::{Hash, Hasher};
+use num_bigint::{BigInt, BigUint, Sign};
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::error::{ParsePart, SciError, SciParseError, SciResult};
+use crate::modules::scientific_notation::format::{self, FormatOptions, DISPLAY_ROUNDING};
+use crate::modules::scientific_notation::notation::Notation;
+use crate::modules::scientific_notation::parse::{self, Fragment};
+use crate::modules::scientific_notation::rounding::RoundingMode;
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+#[derive(Debug, Clone)]
+/// Scientific notation with an arbitrary precision coefficient, for high precision constants and
+/// long accumulations. It has the parsing, display and arithmetic APIs of [SciNote], without its
+/// 28 digits limit: parsing, addition, subtraction and multiplication never lose digits, and
+/// division keeps [BigSciNote::DIVISION_DIGITS] significant digits unless other precision is given.
+///
+/// Like in [SciNote], the coefficient is normalized to one integer digit, `1 <= |coefficient| < 10`,
+/// or it's exactly zero, and the exponent is an i16
+///
+/// ## Conversions:
+/// Every [SciNote], Decimal and integer converts into a BigSciNote, 128 bit integers included.
+/// Converting back fails with a `PrecisionError` if the coefficient has more significant digits
+/// than a Decimal holds, use `round_sig_figs` before converting
+///
+/// ## Differences from SciNote:
+/// There's no `saturating_*` arithmetic, the coefficient has no digits limit so there's no largest
+/// value to clamp to. `to_engineering` and `to_si_prefix` aren't provided either, their Decimal
+/// coefficient can't hold every digit: the `notation` setting displays the value in engineering
+/// notation or with SI prefixes instead
+pub struct BigSciNote {
+    /// Digits of the coefficient, the first one is the integer digit and the others are decimals
+    pub(crate) mantissa: BigInt,
+    pub(crate) exponent: i16,
+    pub(crate) display_decimals: Option<usize>,
+    pub(crate) format: FormatOptions
+}
+
+impl BigSciNote {
+    /// Significant digits kept by [BigSciNote::div]
+    pub const DIVISION_DIGITS: u32 = 50;
+
+    pub fn zero() -> Self {
+        Self {
+            mantissa: BigInt::default(),
+            exponent: 0,
+            display_decimals: None,
+            format: FormatOptions::default()
+        }
+    }
+
+    /// Create the value `mantissa x 10^power`, the mantissa may have any amount of digits: `314159`
+    /// and `-5` are `3.14159x10^0`. Fails with a `SciError::ExponentError` if the exponent of the
+    /// normalized coefficient is out of the i16 range
+    pub fn new(mantissa: BigInt, power: i64) -> SciResult<Self> {
+        Self::from_unbounded(mantissa, power, None, FormatOptions::default())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.sign() == Sign::NoSign
+    }
+
+    /// Parse a number in any syntax accepted by [SciNote::parse_from_str], keeping every digit of
+    /// the coefficient, so `3.14159265358979323846264338327950288x10^0` isn't rounded
+    pub fn parse_from_str(input: &str) -> SciResult<Self> {
+        parse::parse_notation_with(
+            input,
+            parse_coefficient,
+            |(mantissa, _)| mantissa.sign() == Sign::NoSign,
+            |(mantissa, decimals), exponent| {
+                let power = parse::exponent_in_range(exponent.saturating_sub(i128::from(decimals)))?;
+                Self::from_unbounded(mantissa, power, None, FormatOptions::default())
+            }
+        )
+    }
+
+    pub fn add(&self, input2: &Self) -> SciResult<Self> {
+        let (mantissa, power) = self.add_unbounded(input2);
+        Self::from_unbounded(
+            mantissa,
+            power,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
+    }
+
+    pub fn mul(&self, input2: &Self) -> SciResult<Self> {
+        let (mantissa, power) = self.mul_unbounded(input2);
+        Self::from_unbounded(
+            mantissa,
+            power,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
+    }
+
+    /// Divide by `input2`, keeping up to [BigSciNote::DIVISION_DIGITS] significant digits and
+    /// rounding half to even. See [BigSciNote::div_with_precision] to select the precision and rounding
+    pub fn div(&self, input2: &Self) -> SciResult<Self> {
+        self.div_with_precision(input2, Self::DIVISION_DIGITS, SciNote::DIVISION_ROUNDING)
+    }
+
+    /// Divide by `input2`, rounding the result coefficient to `max_significant_digits` with the
+    /// `rounding` mode. There's no upper limit for the precision, but it must be at least 1,
+    /// otherwise a `SciError::PrecisionError` is returned. Dividing by zero returns a
+    /// `SciError::OperationError`
+    pub fn div_with_precision(
+        &self,
+        input2: &Self,
+        max_significant_digits: u32,
+        rounding: RoundingMode
+    ) -> SciResult<Self> {
+        let (mantissa, power) = self.div_rounded(input2, max_significant_digits, rounding)?;
+        Self::from_unbounded(
+            mantissa,
+            power,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
+    }
+
+    /// Round the coefficient to `figures` significant figures. The result is normalized, so a
+    /// rounding that carries into a new integer digit adapts the exponent: `9.99x10^2` rounded up
+    /// to two figures is `1.0x10^3`. Figures must be at least 1, otherwise a
+    /// `SciError::PrecisionError` is returned
+    pub fn round_sig_figs(&self, figures: u32, mode: RoundingMode) -> SciResult<Self> {
+        if figures == 0 {
+            return Err(SciError::PrecisionError(Some("Significant figures must be at least 1".to_string())))
+        }
+
+        let (mantissa, power) = self.to_unbounded();
+        let (mantissa, power, _) = round_significant(&mantissa, power, figures, mode, false);
+        Self::from_unbounded(mantissa, power, self.display_decimals, self.format)
+    }
+
+    /// Round the coefficient to `decimals` decimal places, like [BigSciNote::round_sig_figs]
+    pub fn round_decimals(&self, decimals: u32, mode: RoundingMode) -> SciResult<Self> {
+        self.round_sig_figs(decimals.saturating_add(1), mode)
+    }
+
+    /// Sum both numbers exactly, returning the mantissa and the power of ten of its last digit
+    pub(super) fn add_unbounded(&self, input2: &Self) -> (BigInt, i64) {
+        let (mantissa1, power1) = self.to_unbounded();
+        let (mantissa2, power2) = input2.to_unbounded();
+
+        //  Align both mantissas to the smallest power, it's exact whatever the difference is
+        let power = power1.min(power2);
+        (mantissa1 * pow10((power1 - power) as u32) + mantissa2 * pow10((power2 - power) as u32), power)
+    }
+
+    /// Multiply both numbers exactly, returning the mantissa and the power of ten of its last digit
+    pub(super) fn mul_unbounded(&self, input2: &Self) -> (BigInt, i64) {
+        let (mantissa1, power1) = self.to_unbounded();
+        let (mantissa2, power2) = input2.to_unbounded();
+
+        (mantissa1 * mantissa2, power1 + power2)
+    }
+
+    /// Divide both numbers, returning a quotient with at least a digit more than
+    /// `max_significant_digits`, the power of ten of its last digit, and whether there was a
+    /// remainder. The extra digit and the remainder decide the rounding of the quotient
+    pub(super) fn div_unbounded(&self, input2: &Self, max_significant_digits: u32) -> SciResult<(BigInt, i64, bool)> {
+        if max_significant_digits == 0 {
+            return Err(SciError::PrecisionError(Some("Significant digits must be at least 1".to_string())))
+        }
+        if input2.is_zero() {
+            return Err(SciError::OperationError(Some("Division by zero".to_string())))
+        }
+
+        let (dividend, dividend_power) = self.to_unbounded();
+        let (divisor, divisor_power) = input2.to_unbounded();
+
+        //  Shift the dividend so the quotient has a digit more than the requested ones, the digits
+        // past it only decide the rounding, through the remainder
+        let shift = (i64::from(max_significant_digits) + 1 + digit_count(divisor.magnitude()) as i64
+            - digit_count(dividend.magnitude()) as i64).max(0) as u32;
+        let numerator = dividend.magnitude() * pow10(shift).magnitude();
+        let quotient = &numerator / divisor.magnitude();
+        let remainder_found = &numerator % divisor.magnitude() != BigUint::default();
+
+        let negative = (dividend.sign() == Sign::Minus) != (divisor.sign() == Sign::Minus);
+        let quotient = BigInt::from_biguint(if negative { Sign::Minus } else { Sign::Plus }, quotient);
+
+        Ok((quotient, dividend_power - divisor_power - i64::from(shift), remainder_found))
+    }
+
+    /// Divide both numbers, rounding the quotient once to `max_significant_digits` with the
+    /// `rounding` mode, and returning its mantissa and the power of ten of its last digit
+    pub(super) fn div_rounded(
+        &self,
+        input2: &Self,
+        max_significant_digits: u32,
+        rounding: RoundingMode
+    ) -> SciResult<(BigInt, i64)> {
+        let (quotient, power, remainder_found) = self.div_unbounded(input2, max_significant_digits)?;
+
+        //  Trailing zeros of exact quotients come from the division precision and not from the operands
+        let (mantissa, power, _) =
+            round_significant(&quotient, power, max_significant_digits, rounding, remainder_found);
+        Ok(strip_trailing_zeros(mantissa, power))
+    }
+
+    /// The mantissa, and the power of ten of its last digit
+    pub(super) fn to_unbounded(&self) -> (BigInt, i64) {
+        let digits = digit_count(self.mantissa.magnitude());
+        (self.mantissa.clone(), i64::from(self.exponent) - (digits as i64 - 1))
+    }
+
+    /// Build a new instance from a mantissa and the power of ten of its last digit, failing if the
+    /// exponent of the normalized coefficient is out of the i16 range
+    pub(super) fn from_unbounded(
+        mantissa: BigInt,
+        power: i64,
+        display_decimals: Option<usize>,
+        format: FormatOptions
+    ) -> SciResult<Self> {
+        //  There's a single zero, 0x10^0, regardless of the exponent or sign it was computed with
+        if mantissa.sign() == Sign::NoSign {
+            return Ok(Self {
+                mantissa: BigInt::default(),
+                exponent: 0,
+                display_decimals,
+                format
+            })
+        }
+
+        let exponent = power + digit_count(mantissa.magnitude()) as i64 - 1;
+        let exponent = i16::try_from(exponent)
+            .map_err(|_| SciError::ExponentError(Some(format!("Exponent {} out of range", exponent))))?;
+
+        Ok(Self {
+            mantissa,
+            exponent,
+            display_decimals,
+            format
+        })
+    }
+
+    /// Coefficient and exponent as they're displayed, see [SciNote] for the rounding rules
+    pub(super) fn display_parts(&self, precision: Option<usize>) -> (String, i64) {
+        let (decimals, keep_trailing_zeros) = match (precision, self.format.significant_figures) {
+            (Some(precision), _) => (precision, true),
+            (None, Some(figures)) => (figures.saturating_sub(1), true),
+            (None, None) => (self.display_decimals.unwrap_or(2), false)
+        };
+        let rounding = self.format.rounding.unwrap_or(DISPLAY_ROUNDING);
+
+        //  Like in SciNote, significant figures are counted on the normalized coefficient, and
+        // decimals on the displayed one, which has up to two more integer digits in engineering notation
+        let engineering = self.is_engineering();
+        let figures_only = precision.is_none() && self.format.significant_figures.is_some();
+        let integer_digits = |exponent: i64| {
+            if engineering && !figures_only {
+                exponent.rem_euclid(3) as usize + 1
+            } else {
+                1
+            }
+        };
+
+        let (mantissa, power) = self.to_unbounded();
+        let kept_digits = decimals.saturating_add(integer_digits(i64::from(self.exponent)));
+        let figures = u32::try_from(kept_digits).unwrap_or(u32::MAX);
+        let (mantissa, power, _) = round_significant(&mantissa, power, figures, rounding, false);
+
+        //  A rounding that carries into a new digit leaves a power of ten, the digits past the
+        // decimals of its exponent are zeros
+        let mut digits = mantissa.magnitude().to_string();
+        let exponent = power + digits.len() as i64 - 1;
+        let kept_digits = decimals.saturating_add(integer_digits(exponent));
+        digits.truncate(kept_digits);
+        if keep_trailing_zeros && digits.len() < kept_digits {
+            digits.push_str(&"0".repeat(kept_digits - digits.len()));
+        }
+
+        self.place_point(digits, mantissa.sign() == Sign::Minus, exponent)
+    }
+
+    /// Coefficient and exponent of the E-notation, the whole coefficient unless it's limited
+    pub(super) fn exp_parts(&self, precision: Option<usize>) -> (String, i64) {
+        if precision.is_none() && self.display_decimals.is_none() && self.format.significant_figures.is_none() {
+            let digits = self.mantissa.magnitude().to_string();
+            return self.place_point(digits, self.mantissa.sign() == Sign::Minus, i64::from(self.exponent))
+        }
+        self.display_parts(precision)
+    }
+
+    fn is_engineering(&self) -> bool {
+        matches!(self.format.notation, Some(Notation::Engineering | Notation::SiPrefix))
+    }
+
+    /// Write the digits of a coefficient with the point after its integer digits: the first one,
+    /// or up to three in engineering notation, padded with zeros, so `1x10^4` is `10x10^3`
+    fn place_point(&self, mut digits: String, negative: bool, exponent: i64) -> (String, i64) {
+        let shift = if self.is_engineering() { exponent.rem_euclid(3) as usize } else { 0 };
+        if digits.len() < shift + 1 {
+            digits.push_str(&"0".repeat(shift + 1 - digits.len()));
+        }
+
+        let sign = if negative { "-" } else { "" };
+        let coefficient = match digits.split_at(shift + 1) {
+            (integer, "") => format!("{}{}", sign, integer),
+            (integer, decimals) => format!("{}{}.{}", sign, integer, decimals)
+        };
+        (coefficient, exponent - shift as i64)
+    }
+}
+
+/// Parse the coefficient into its digits and the amount of them after the point
+pub(super) fn parse_coefficient(coefficient: Fragment) -> SciResult<(BigInt, u32)> {
+    if coefficient.text.is_empty() {
+        return Err(SciParseError::new(ParsePart::Coefficient, coefficient.position, "", "missing coefficient").into())
+    }
+
+    parse::check_number_characters(coefficient, ParsePart::Coefficient, true)?;
+
+    let unsigned = coefficient.text.trim_start_matches(['+', '-']);
+    let (integer, decimals) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let magnitude = format!("{}{}", integer, decimals)
+        .parse::<BigInt>()
+        .map_err(|error| SciError::Unexpected(format!("Unexpected coefficient parse error: {}", error)))?;
+
+    let mantissa = if coefficient.text.starts_with('-') { -magnitude } else { magnitude };
+    Ok((mantissa, decimals.len() as u32))
+}
+
+/// 10^exponent
+fn pow10(exponent: u32) -> BigInt {
+    BigInt::from(10u32).pow(exponent)
+}
+
+/// Amount of decimal digits of the magnitude, zero has one
+pub(super) fn digit_count(magnitude: &BigUint) -> u32 {
+    if let Ok(small) = u64::try_from(magnitude) {
+        return small.checked_ilog10().unwrap_or(0) + 1
+    }
+
+    //  2^(bits - 1) <= magnitude < 2^bits, so it has the digits of 2^(bits - 1) or one more. The
+    // float estimate may be off by one either way, the powers around it decide the exact count
+    let estimate = ((magnitude.bits() - 1) as f64 * std::f64::consts::LOG10_2) as u32 + 1;
+    let lower = pow10(estimate - 1).magnitude().clone();
+    if *magnitude < lower {
+        estimate - 1
+    } else if *magnitude >= lower * 10u32 {
+        estimate + 1
+    } else {
+        estimate
+    }
+}
+
+/// Drop the trailing zeros of the mantissa, raising its power
+pub(super) fn strip_trailing_zeros(mut mantissa: BigInt, mut power: i64) -> (BigInt, i64) {
+    if mantissa.sign() == Sign::NoSign {
+        return (mantissa, power)
+    }
+
+    while mantissa.magnitude() % 10u32 == BigUint::default() {
+        mantissa /= 10u32;
+        power += 1;
+    }
+    (mantissa, power)
+}
+
+/// Round the mantissa to `figures` significant digits, returning the rounded mantissa, the power
+/// of its last digit and whether any digit was dropped. `sticky` tells that there are more digits
+/// past the mantissa, lost before rounding, so it's slightly bigger in magnitude than it looks
+pub(super) fn round_significant(
+    mantissa: &BigInt,
+    power: i64,
+    figures: u32,
+    mode: RoundingMode,
+    sticky: bool
+) -> (BigInt, i64, bool) {
+    let magnitude = mantissa.magnitude();
+    let negative = mantissa.sign() == Sign::Minus;
+    let dropped = digit_count(magnitude).saturating_sub(figures);
+    if dropped == 0 && !sticky {
+        return (mantissa.clone(), power, false)
+    }
+
+    let divisor = pow10(dropped).magnitude().clone();
+    let mut quotient = magnitude / &divisor;
+    let remainder = magnitude % &divisor;
+    let inexact = sticky || remainder != BigUint::default();
+
+    //  Compare the dropped digits against half a unit of the last kept digit
+    let half = match (&remainder * 2u32).cmp(&divisor) {
+        Ordering::Equal if sticky => Ordering::Greater,
+        half => half
+    };
+    let away_from_zero = match mode {
+        RoundingMode::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && quotient.bit(0)),
+        RoundingMode::HalfAwayFromZero => half != Ordering::Less,
+        RoundingMode::HalfTowardZero => half == Ordering::Greater,
+        RoundingMode::AwayFromZero => inexact,
+        RoundingMode::TowardZero => false,
+        RoundingMode::Ceiling => inexact && !negative,
+        RoundingMode::Floor => inexact && negative
+    };
+
+    let mut power = power + i64::from(dropped);
+    if away_from_zero {
+        quotient += 1u32;
+
+        //  A carry into a new digit, 999 -> 1000, leaves an extra trailing zero
+        if digit_count(&quotient) > figures {
+            quotient /= 10u32;
+            power += 1;
+        }
+    }
+
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    (BigInt::from_biguint(sign, quotient), power, inexact)
+}
+
+impl PartialEq for BigSciNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigSciNote {}
+
+impl PartialOrd for BigSciNote {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigSciNote {
+    fn cmp(&self, other: &Self) -> Ordering {
+        //  First, compare signs. Zero is compared as its own sign
+        let self_sign = self.mantissa.sign();
+        let other_sign = other.mantissa.sign();
+        if self_sign != other_sign || self_sign == Sign::NoSign {
+            return self_sign.cmp(&other_sign)
+        }
+
+        //  Same sign, with one integer digit coefficients the bigger exponent has the bigger
+        // magnitude. Otherwise, compare the digits aligned to the same length
+        let magnitude = self.exponent.cmp(&other.exponent).then_with(|| {
+            let self_digits = digit_count(self.mantissa.magnitude());
+            let other_digits = digit_count(other.mantissa.magnitude());
+            let length = self_digits.max(other_digits);
+
+            (self.mantissa.magnitude() * pow10(length - self_digits).magnitude())
+                .cmp(&(other.mantissa.magnitude() * pow10(length - other_digits).magnitude()))
+        });
+
+        //  For negative numbers, the bigger magnitude is the smaller number
+        if self_sign == Sign::Minus {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl Hash for BigSciNote {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        //  Trailing zeros don't change the value, 1.0 and 1 have the same hash
+        let (mantissa, _) = strip_trailing_zeros(self.mantissa.clone(), 0);
+        mantissa.hash(state);
+        self.exponent.hash(state);
+    }
+}
+
+impl From<SciNote> for BigSciNote {
+    fn from(value: SciNote) -> Self {
+        Self {
+            mantissa: BigInt::from(value.coefficient.mantissa()),
+            exponent: value.exponent,
+            display_decimals: value.display_decimals,
+            format: value.format
+        }
+    }
+}
+
+impl From<Decimal> for BigSciNote {
+    fn from(value: Decimal) -> Self {
+        Self::from(SciNote::from(value))
+    }
+}
+
+macro_rules! impl_integer_conversions {
+    ($($integer:ty),*) => {
+        $(
+            /// Every integer is kept whole, 128 bit ones included
+            impl From<$integer> for BigSciNote {
+                fn from(value: $integer) -> Self {
+                    //  Trailing zeros aren't kept in the mantissa, the exponent of an integer always fits
+                    let (mantissa, power) = strip_trailing_zeros(BigInt::from(value), 0);
+                    BigSciNote::new(mantissa, power).expect("an integer always fits in a BigSciNote")
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_conversions!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl TryFrom<BigSciNote> for SciNote {
+    type Error = SciError;
+    fn try_from(value: BigSciNote) -> Result<Self, Self::Error> {
+        //  Trailing zeros aren't significant, only the remaining digits have to fit in a Decimal
+        let (mantissa, _) = strip_trailing_zeros(value.mantissa.clone(), 0);
+        let digits = digit_count(mantissa.magnitude());
+        let too_precise = || SciError::PrecisionError(Some(format!(
+            "Coefficient with {} significant digits can't be held by a Decimal", digits
+        )));
+
+        let mantissa = i128::try_from(&mantissa).map_err(|_| too_precise())?;
+        let coefficient = Decimal::try_from_i128_with_scale(mantissa, digits - 1)
+            .map_err(|_| too_precise())?;
+
+        Ok(SciNote {
+            coefficient,
+            exponent: value.exponent,
+            display_decimals: value.display_decimals,
+            format: value.format
+        })
+    }
+}
+
+/// Precision, width, fill, alignment and `+` flags are honored like in [SciNote]
+impl Display for BigSciNote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision();
+        let text = |(coefficient, exponent): (String, i64)| (coefficient, i128::from(exponent));
+
+        format::write_notation(
+            f,
+            text(self.display_parts(precision)),
+            self.format.notation == Some(Notation::SiPrefix),
+            || text(self.clone().notation(Notation::Scientific).display_parts(precision))
+        )
+    }
+}
+
+impl LowerExp for BigSciNote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (coefficient, exponent) = self.exp_parts(f.precision());
+        format::pad_notation(f, &coefficient, &format!("e{}", exponent))
+    }
+}
+
+impl UpperExp for BigSciNote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (coefficient, exponent) = self.exp_parts(f.precision());
+        format::pad_notation(f, &coefficient, &format!("E{}", exponent))
+    }
+}
@@ -0,0 +1,46 @@
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::error::SciResult;
+use crate::modules::scientific_notation::format::FormatOptions;
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+/// Builder of a [SciNote] from a coefficient and its exponent, started by [SciNote::build]. Both
+/// parts are kept as they're given, in any order, and the coefficient is only normalized when the
+/// value is finished, so `coefficient(150).exponent(0)` and `exponent(0).coefficient(150)` are
+/// both `1.5x10^2`
+#[derive(Debug, Clone, Copy)]
+pub struct SciNoteBuilder {
+    coefficient: Decimal,
+    exponent: i16
+}
+
+impl SciNoteBuilder {
+    /// Set the coefficient, it may have any amount of integer digits
+    pub fn coefficient(mut self, coefficient: Decimal) -> Self {
+        self.coefficient = coefficient;
+        self
+    }
+
+    /// Set the exponent of the coefficient given to the builder
+    pub fn exponent(mut self, exponent: i16) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Build the value, normalizing the coefficient to one integer digit and adapting the exponent.
+    /// A zero coefficient builds the canonical zero, whatever the exponent is.
+    ///
+    /// Fails with a `SciError::ExponentError` if the adapted exponent is out of the i16 range
+    pub fn finish(self) -> SciResult<SciNote> {
+        let (coefficient, exponent) = SciNote::scale_unbounded(self.coefficient, i32::from(self.exponent))?;
+        SciNote::from_unbounded(coefficient, exponent, None, FormatOptions::default())
+    }
+}
+
+impl Default for SciNoteBuilder {
+    fn default() -> Self {
+        Self {
+            coefficient: Decimal::ONE,
+            exponent: 0
+        }
+    }
+}
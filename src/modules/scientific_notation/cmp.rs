@@ -0,0 +1,76 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::modules::scientific_notation::wide::WideSciNote;
+
+/// Implement equality, ordering and hashing by value for a type with a normalized `coefficient`
+/// Decimal and an integer `exponent`. The display settings don't take part in them
+macro_rules! impl_ordering {
+    ($type:ident) => {
+        impl PartialEq for $type {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for $type {}
+
+        impl PartialOrd for $type {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $type {
+            fn cmp(&self, other: &Self) -> Ordering {
+                compare(
+                    (self.coefficient, i128::from(self.exponent)),
+                    (other.coefficient, i128::from(other.exponent))
+                )
+            }
+        }
+
+        impl Hash for $type {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                //  All zeros are equal, regardless of their exponent
+                if self.coefficient.is_zero() {
+                    0i32.hash(state);
+                    return
+                }
+
+                //  Decimal hashes by value, 1.0 and 1 have the same hash
+                self.coefficient.hash(state);
+                self.exponent.hash(state);
+            }
+        }
+    };
+}
+
+impl_ordering!(SciNote);
+impl_ordering!(WideSciNote);
+
+/// Compare two normalized coefficient and exponent pairs by value
+fn compare(
+    (self_coefficient, self_exponent): (Decimal, i128),
+    (other_coefficient, other_exponent): (Decimal, i128)
+) -> Ordering {
+    //  First, compare signs. Zero is compared as its own sign, whatever its exponent is
+    let self_sign = self_coefficient.cmp(&Decimal::ZERO);
+    let other_sign = other_coefficient.cmp(&Decimal::ZERO);
+    if self_sign != other_sign || self_sign == Ordering::Equal {
+        return self_sign.cmp(&other_sign)
+    }
+
+    //  Same sign, with one integer digit coefficients the bigger exponent has the bigger magnitude
+    let magnitude = self_exponent
+        .cmp(&other_exponent)
+        .then_with(|| self_coefficient.abs().cmp(&other_coefficient.abs()));
+
+    //  For negative numbers, the bigger magnitude is the smaller number
+    if self_sign == Ordering::Less {
+        magnitude.reverse()
+    } else {
+        magnitude
+    }
+}
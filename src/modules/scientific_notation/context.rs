@@ -0,0 +1,292 @@
+use std::fmt::{Display, Formatter};
+use num_bigint::{BigInt, Sign};
+use crate::modules::scientific_notation::big::{self, BigSciNote};
+use crate::modules::scientific_notation::error::{SciError, SciResult};
+use crate::modules::scientific_notation::format::{FormatOptions, MAX_SIGNIFICANT_FIGURES};
+use crate::modules::scientific_notation::rounding::RoundingMode;
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+/// Conditions signalled by the operations run under a [SciContext]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// Nonzero digits were discarded by the rounding, the result isn't exact
+    Inexact,
+    /// Digits were discarded by the rounding, even if they were all zeros
+    Rounded,
+    /// The exponent of the result was above the maximum exponent of the context
+    Overflow,
+    /// The exponent of the result was below the minimum exponent of the context
+    Underflow,
+    /// A nonzero number was divided by zero
+    DivisionByZero
+}
+
+impl Signal {
+    /// Every signal, in the order the flags are listed and the traps are checked
+    const ALL: [Signal; 5] = [
+        Signal::DivisionByZero,
+        Signal::Overflow,
+        Signal::Underflow,
+        Signal::Inexact,
+        Signal::Rounded
+    ];
+
+    /// Bit of the signal in the flags and traps masks
+    fn mask(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Error returned when the signal is trapped during the `operation`
+    fn error(self, operation: &str) -> SciError {
+        let message = Some(format!("Signal '{}' trapped in {}", self, operation));
+        match self {
+            Signal::Inexact | Signal::Rounded => SciError::PrecisionError(message),
+            Signal::Overflow | Signal::Underflow => SciError::ExponentError(message),
+            Signal::DivisionByZero => SciError::OperationError(message)
+        }
+    }
+}
+
+impl Display for Signal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Signal::Inexact => write!(f, "inexact"),
+            Signal::Rounded => write!(f, "rounded"),
+            Signal::Overflow => write!(f, "overflow"),
+            Signal::Underflow => write!(f, "underflow"),
+            Signal::DivisionByZero => write!(f, "division by zero")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Arithmetic context following the IEEE 854 model, like Python's `decimal.Context`. The results
+/// of the `*_with_context` methods of [SciNote] are rounded to the precision of the context with
+/// its rounding mode, and checked against its exponent bounds.
+///
+/// Every rounding, overflow, underflow and division by zero raises a sticky flag, that stays set
+/// until [SciContext::clear_flags] is called, so the flags tell whether any operation of a chain
+/// lost digits. Trapped signals return a `SciError` from the operation that raised them instead,
+/// pointing to the exact step: `Inexact` and `Rounded` return a `PrecisionError`, `Overflow` and
+/// `Underflow` an `ExponentError`, and `DivisionByZero` an `OperationError`. Flags are raised
+/// even when the signal is trapped.
+///
+/// Without infinities nor subnormals, untrapped signals give the closest representable result:
+/// an overflow, or a division by zero, gives the largest value of the context with the sign of
+/// the result, and an underflow gives zero.
+///
+/// ## Defaults:
+/// 28 significant digits, [RoundingMode::HalfEven], the i16 exponent range of [SciNote], and
+/// `Overflow` and `DivisionByZero` trapped
+pub struct SciContext {
+    precision: u32,
+    rounding: RoundingMode,
+    min_exponent: i16,
+    max_exponent: i16,
+    flags: u8,
+    traps: u8
+}
+
+impl SciContext {
+    /// Round results to `digits` significant digits. The digits are limited between 1 and 28, the
+    /// precision of the coefficient
+    pub fn precision(mut self, digits: u32) -> Self {
+        self.precision = digits.clamp(1, MAX_SIGNIFICANT_FIGURES as u32);
+        self
+    }
+
+    /// Round results with `mode`
+    pub fn rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = mode;
+        self
+    }
+
+    /// Signal an overflow for results with an exponent above `max`, and an underflow for results
+    /// with an exponent below `min`
+    ///
+    /// ## Panics
+    /// If `min` is greater than `max`
+    pub fn exponent_range(mut self, min: i16, max: i16) -> Self {
+        assert!(min <= max, "attempt to set an empty exponent range: {} > {}", min, max);
+        self.min_exponent = min;
+        self.max_exponent = max;
+        self
+    }
+
+    /// Return a `SciError` from the operations that raise the `signal`
+    pub fn trap(mut self, signal: Signal) -> Self {
+        self.traps |= signal.mask();
+        self
+    }
+
+    /// Let the operations that raise the `signal` return the closest representable result
+    pub fn untrap(mut self, signal: Signal) -> Self {
+        self.traps &= !signal.mask();
+        self
+    }
+
+    pub fn is_trapped(&self, signal: Signal) -> bool {
+        self.traps & signal.mask() != 0
+    }
+
+    /// Whether the `signal` was raised since the flags were last cleared
+    pub fn is_flagged(&self, signal: Signal) -> bool {
+        self.flags & signal.mask() != 0
+    }
+
+    /// Signals raised since the flags were last cleared
+    pub fn flags(&self) -> Vec<Signal> {
+        Signal::ALL.into_iter().filter(|signal| self.is_flagged(*signal)).collect()
+    }
+
+    pub fn clear_flags(&mut self) {
+        self.flags = 0;
+    }
+
+    /// Raise the `signals`, returning the error of the first trapped one
+    fn raise(&mut self, signals: &[Signal], operation: &str) -> SciResult<()> {
+        for signal in signals {
+            self.flags |= signal.mask();
+        }
+
+        match Signal::ALL.into_iter().find(|signal| signals.contains(signal) && self.is_trapped(*signal)) {
+            Some(signal) => Err(signal.error(operation)),
+            None => Ok(())
+        }
+    }
+
+    /// Largest value of the context, the precision digits set to nine and the maximum exponent
+    fn largest(&self, negative: bool) -> (BigInt, i64) {
+        let nines = BigInt::from(10u32).pow(self.precision) - 1u32;
+        let power = i64::from(self.max_exponent) - i64::from(self.precision) + 1;
+        (if negative { -nines } else { nines }, power)
+    }
+
+    /// Round an exact result, a mantissa and the power of ten of its last digit, and check it
+    /// against the exponent bounds. `sticky` tells that there are nonzero digits past the mantissa
+    fn finish(
+        &mut self,
+        mantissa: BigInt,
+        power: i64,
+        sticky: bool,
+        display_decimals: Option<usize>,
+        format: FormatOptions,
+        operation: &str
+    ) -> SciResult<SciNote> {
+        let rounded = sticky || big::digit_count(mantissa.magnitude()) > self.precision;
+        let (mantissa, power, inexact) = big::round_significant(&mantissa, power, self.precision, self.rounding, sticky);
+
+        let mut signals = Vec::new();
+        if inexact {
+            signals.push(Signal::Inexact);
+        }
+        if rounded {
+            signals.push(Signal::Rounded);
+        }
+
+        //  The exponent of the normalized coefficient, zero has no exponent to check
+        let exponent = power + i64::from(big::digit_count(mantissa.magnitude())) - 1;
+        let (mantissa, power) = if mantissa.sign() == Sign::NoSign {
+            (mantissa, power)
+        } else if exponent > i64::from(self.max_exponent) {
+            signals.extend([Signal::Overflow, Signal::Inexact, Signal::Rounded]);
+            self.largest(mantissa.sign() == Sign::Minus)
+        } else if exponent < i64::from(self.min_exponent) {
+            signals.extend([Signal::Underflow, Signal::Inexact, Signal::Rounded]);
+            (BigInt::default(), 0)
+        } else {
+            (mantissa, power)
+        };
+
+        self.raise(&signals, operation)?;
+        SciNote::try_from(BigSciNote::from_unbounded(mantissa, power, display_decimals, format)?)
+    }
+}
+
+impl Default for SciContext {
+    fn default() -> Self {
+        Self {
+            precision: MAX_SIGNIFICANT_FIGURES as u32,
+            rounding: RoundingMode::HalfEven,
+            min_exponent: i16::MIN,
+            max_exponent: i16::MAX,
+            flags: 0,
+            traps: Signal::Overflow.mask() | Signal::DivisionByZero.mask()
+        }
+    }
+}
+
+/// Arithmetic under a [SciContext]. The operation is computed exactly, then rounded once to the
+/// precision of the context, raising its signals
+impl SciNote {
+    /// Add under the `context`. Digits are never lost before the rounding of the context, however
+    /// far apart the operands are
+    pub fn add_with_context(&self, input2: &Self, context: &mut SciContext) -> SciResult<Self> {
+        let (mantissa, power) = BigSciNote::from(*self).add_unbounded(&BigSciNote::from(*input2));
+        context.finish(
+            mantissa,
+            power,
+            false,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format),
+            "add"
+        )
+    }
+
+    /// Subtract under the `context`, like [SciNote::add_with_context]
+    pub fn sub_with_context(&self, input2: &Self, context: &mut SciContext) -> SciResult<Self> {
+        self.add_with_context(&-input2, context)
+    }
+
+    /// Multiply under the `context`. The exact product may have up to 58 digits before rounding
+    pub fn mul_with_context(&self, input2: &Self, context: &mut SciContext) -> SciResult<Self> {
+        let (mantissa, power) = BigSciNote::from(*self).mul_unbounded(&BigSciNote::from(*input2));
+        context.finish(
+            mantissa,
+            power,
+            false,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format),
+            "multiply"
+        )
+    }
+
+    /// Divide under the `context`. Exact quotients that fit in the precision don't raise any flag,
+    /// `1/4` is `2.5x10^-1`, and quotients like `1/3` are `Inexact` and `Rounded`.
+    ///
+    /// Dividing a nonzero number by zero raises `DivisionByZero`. Dividing zero by zero is
+    /// undefined, and always returns a `SciError::OperationError`
+    pub fn div_with_context(&self, input2: &Self, context: &mut SciContext) -> SciResult<Self> {
+        let display_decimals = self.display_decimals.or(input2.display_decimals);
+        let format = self.format.or(input2.format);
+
+        if input2.is_zero() {
+            if self.is_zero() {
+                return Err(SciError::OperationError(Some("Division of zero by zero".to_string())))
+            }
+
+            context.raise(&[Signal::DivisionByZero], "divide")?;
+            let (mantissa, power) = context.largest(self.coefficient.is_sign_negative());
+            return SciNote::try_from(BigSciNote::from_unbounded(mantissa, power, display_decimals, format)?)
+        }
+
+        let (quotient, power, remainder_found) =
+            BigSciNote::from(*self).div_unbounded(&BigSciNote::from(*input2), context.precision)?;
+
+        //  Trailing zeros of exact quotients come from the division shift and not from the operands
+        let (quotient, power) = if remainder_found {
+            (quotient, power)
+        } else {
+            big::strip_trailing_zeros(quotient, power)
+        };
+
+        context.finish(quotient, power, remainder_found, display_decimals, format, "divide")
+    }
+
+    /// Round the value to the precision of the `context` and check it against its exponent bounds,
+    /// like the result of an operation
+    pub fn round_with_context(&self, context: &mut SciContext) -> SciResult<Self> {
+        let (mantissa, power) = BigSciNote::from(*self).to_unbounded();
+        context.finish(mantissa, power, false, self.display_decimals, self.format, "round")
+    }
+}
@@ -0,0 +1,218 @@
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::error::{SciError, SciResult};
+use crate::modules::scientific_notation::format::FormatOptions;
+use crate::modules::scientific_notation::parse;
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+/// Significant digits kept when converting from a float, the most a Decimal can always hold
+const FLOAT_SIGNIFICANT_DIGITS: usize = 28;
+
+/// Largest magnitude a Decimal mantissa can hold, 2^96 - 1
+const MAX_DECIMAL_MANTISSA: u128 = (1 << 96) - 1;
+
+impl SciNote {
+    /// Closest f64 to this number. Values too big for an f64 return infinity and values too small
+    /// return zero, like float literals do
+    pub fn to_f64(&self) -> f64 {
+        //  The standard float parser rounds correctly for any exponent, and the exponent is never
+        // applied to the Decimal, so it can't overflow
+        format!("{}e{}", self.coefficient, self.exponent)
+            .parse::<f64>()
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Closest f32 to this number. Values too big for an f32 return infinity and values too small
+    /// return zero, like float literals do
+    pub fn to_f32(&self) -> f32 {
+        format!("{}e{}", self.coefficient, self.exponent)
+            .parse::<f32>()
+            .unwrap_or(f32::NAN)
+    }
+
+    /// Build from an integer split into its sign and magnitude. Trailing zeros are moved to the
+    /// exponent, so only 128 bit integers with more significant digits than a Decimal can hold
+    /// fail, with a `PrecisionError`
+    fn from_integer(negative: bool, mut magnitude: u128) -> SciResult<Self> {
+        let mut exponent = 0i32;
+
+        //  Trailing zeros don't need to be stored in the coefficient
+        while magnitude != 0 && magnitude.is_multiple_of(10) {
+            magnitude /= 10;
+            exponent += 1;
+        }
+
+        if magnitude > MAX_DECIMAL_MANTISSA {
+            let sign = if negative { "-" } else { "" };
+            return Err(SciError::PrecisionError(Some(format!(
+                "{}{}x10^{} has more significant digits than a Decimal can hold", sign, magnitude, exponent
+            ))))
+        }
+
+        let mut coefficient = Decimal::from_i128_with_scale(magnitude as i128, 0);
+        coefficient.set_sign_negative(negative);
+
+        //  At most 38 trailing zeros were moved, the exponent always fits
+        let (coefficient, exponent) = Self::scale_unbounded(coefficient, exponent)?;
+        Self::from_unbounded(coefficient, exponent, None, FormatOptions::default())
+    }
+
+    /// Split the number into the sign and magnitude of an integer. Fails with a `PrecisionError`
+    /// if it has a fractional part, or with an `OutOfRangeError` if it doesn't fit in 128 bits
+    fn to_integer(self) -> SciResult<(bool, u128)> {
+        if self.is_zero() {
+            return Ok((false, 0))
+        }
+
+        //  Places the decimal point has to move to the right to leave an integer mantissa
+        let coefficient = self.coefficient.normalize();
+        let zeros = i32::from(self.exponent) - coefficient.scale() as i32;
+        if zeros < 0 {
+            return Err(SciError::PrecisionError(Some(format!("{} has a fractional part", self))))
+        }
+
+        let magnitude = u32::try_from(zeros)
+            .ok()
+            .and_then(|zeros| 10u128.checked_pow(zeros))
+            .and_then(|power| coefficient.mantissa().unsigned_abs().checked_mul(power))
+            .ok_or(SciError::OutOfRangeError(Some(format!("{} doesn't fit in 128 bits", self))))?;
+
+        Ok((coefficient.is_sign_negative(), magnitude))
+    }
+
+    /// Build from the exact binary value of a float. If it has more than 28 significant digits,
+    /// it's rounded to 28
+    fn from_float(value: f64) -> SciResult<Self> {
+        if !value.is_finite() {
+            return Err(SciError::ConversionError(Some(format!("{} can't be converted to SciNote", value))))
+        }
+
+        //  Float formatting with a precision prints the exact binary value, correctly rounded
+        let exact = format!("{:.*e}", FLOAT_SIGNIFICANT_DIGITS - 1, value);
+        let (coefficient, exponent) = parse::parse_notation(&exact)?;
+
+        //  Trailing zeros come from the formatting precision, they're not significant
+        let (coefficient, exponent) = Self::scale_unbounded(coefficient.normalize(), exponent)?;
+        Self::from_unbounded(coefficient, exponent, None, FormatOptions::default())
+    }
+}
+
+impl TryFrom<f64> for SciNote {
+    type Error = SciError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        SciNote::from_float(value)
+    }
+}
+
+impl TryFrom<f32> for SciNote {
+    type Error = SciError;
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        //  Every f32 is exactly representable as an f64
+        SciNote::from_float(f64::from(value))
+    }
+}
+
+macro_rules! impl_integer_conversions {
+    //  Only the conversion to the integer, for the integers that can't always be converted from
+    (@to $integer:ty) => {
+        impl TryFrom<SciNote> for $integer {
+            type Error = SciError;
+            fn try_from(value: SciNote) -> Result<Self, Self::Error> {
+                let (negative, magnitude) = value.to_integer()?;
+                let out_of_range = || SciError::OutOfRangeError(Some(format!(
+                    "{} doesn't fit in {}", value, stringify!($integer)
+                )));
+
+                if negative {
+                    0i128
+                        .checked_sub_unsigned(magnitude)
+                        .and_then(|integer| <$integer>::try_from(integer).ok())
+                        .ok_or_else(out_of_range)
+                } else {
+                    <$integer>::try_from(magnitude).map_err(|_| out_of_range())
+                }
+            }
+        }
+    };
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for SciNote {
+                fn from(value: $integer) -> Self {
+                    #[allow(unused_comparisons)]
+                    let negative = value < 0;
+                    SciNote::from_integer(negative, (value as i128).unsigned_abs())
+                        .expect("an integer up to 64 bits always fits in a SciNote")
+                }
+            }
+
+            impl_integer_conversions!(@to $integer);
+        )*
+    };
+}
+
+impl_integer_conversions!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_integer_conversions!(@to i128);
+
+/// 128 bit integers whose digits, without the trailing zeros, are above 2^96 - 1 don't fit in the
+/// coefficient, and fail with a `PrecisionError` instead of being rounded
+impl TryFrom<i128> for SciNote {
+    type Error = SciError;
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        SciNote::from_integer(value < 0, value.unsigned_abs())
+    }
+}
+
+//  u128 can't be cast to i128 without losing its top bit, so it's converted on its own
+impl TryFrom<u128> for SciNote {
+    type Error = SciError;
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        SciNote::from_integer(false, value)
+    }
+}
+
+impl TryFrom<SciNote> for u128 {
+    type Error = SciError;
+    fn try_from(value: SciNote) -> Result<Self, Self::Error> {
+        match value.to_integer()? {
+            (false, magnitude) => Ok(magnitude),
+            (true, _) => Err(SciError::OutOfRangeError(Some(format!("{} doesn't fit in u128", value))))
+        }
+    }
+}
+
+impl From<Decimal> for SciNote {
+    fn from(value: Decimal) -> Self {
+        //  A Decimal has at most 28 integer or decimal places, the exponent always fits
+        SciNote::scale_unbounded(value, 0)
+            .and_then(|(coefficient, exponent)| SciNote::from_unbounded(coefficient, exponent, None, FormatOptions::default()))
+            .expect("a Decimal always fits in a SciNote")
+    }
+}
+
+impl TryFrom<SciNote> for Decimal {
+    type Error = SciError;
+    fn try_from(value: SciNote) -> Result<Self, Self::Error> {
+        if value.is_zero() {
+            return Ok(Decimal::ZERO)
+        }
+
+        //  Move the decimal point of the coefficient by the exponent
+        let coefficient = value.coefficient.normalize();
+        let scale = coefficient.scale() as i32 - i32::from(value.exponent);
+
+        if scale > 28 {
+            return Err(SciError::PrecisionError(Some(format!("{} has more decimal places than a Decimal can hold", value))))
+        }
+
+        let mantissa = if scale < 0 {
+            10i128
+                .checked_pow(scale.unsigned_abs())
+                .and_then(|power| coefficient.mantissa().checked_mul(power))
+        } else {
+            Some(coefficient.mantissa())
+        };
+
+        mantissa
+            .and_then(|mantissa| Decimal::try_from_i128_with_scale(mantissa, scale.max(0) as u32).ok())
+            .ok_or(SciError::OutOfRangeError(Some(format!("{} doesn't fit in a Decimal", value))))
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
 use rust_decimal::Error;
 
 pub type SciResult<R> = Result<R, SciError>;
@@ -11,12 +13,70 @@ pub enum SciError {
     GenericError(Option<String>),
     OperationError(Option<String>),
     OutOfRangeError(Option<String>),
-    ParseError(Option<String>),
+    ParseError(SciParseError),
     PrecisionError(Option<String>),
     ScaleError(Option<String>),
     Unexpected(String)
 }
 
+/// Part of the notation where parsing failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePart {
+    Coefficient,
+    Separator,
+    Exponent,
+    /// The SI prefix that replaces the exponent, like the `k` in `4.7k`
+    Prefix
+}
+
+/// Where and why parsing failed. The position is a byte offset into the original input, and the
+/// fragment is the offending text found there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SciParseError {
+    part: ParsePart,
+    position: usize,
+    fragment: String,
+    message: String
+}
+
+impl SciParseError {
+    pub(crate) fn new(part: ParsePart, position: usize, fragment: &str, message: impl Into<String>) -> Self {
+        Self {
+            part,
+            position,
+            fragment: fragment.to_string(),
+            message: message.into()
+        }
+    }
+
+    pub fn part(&self) -> ParsePart {
+        self.part
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn fragment(&self) -> &str {
+        &self.fragment
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Byte range of the offending fragment in the original input, to underline it
+    pub fn span(&self) -> Range<usize> {
+        self.position..self.position + self.fragment.len()
+    }
+}
+
+impl From<SciParseError> for SciError {
+    fn from(value: SciParseError) -> Self {
+        Self::ParseError(value)
+    }
+}
+
 impl From<Error> for SciError {
     fn from(value: Error) -> Self {
         match value {
@@ -30,3 +90,46 @@ impl From<Error> for SciError {
     }
 }
 
+impl Display for ParsePart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePart::Coefficient => write!(f, "coefficient"),
+            ParsePart::Separator => write!(f, "separator"),
+            ParsePart::Exponent => write!(f, "exponent"),
+            ParsePart::Prefix => write!(f, "prefix")
+        }
+    }
+}
+
+impl Display for SciParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {} at byte {}: {}", self.part, self.position, self.message)
+    }
+}
+
+impl Display for SciError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (kind, message) = match self {
+            SciError::CoefficientError(message) => ("coefficient error", message.as_deref()),
+            SciError::ConversionError(message) => ("conversion error", message.as_deref()),
+            SciError::ExponentError(message) => ("exponent error", message.as_deref()),
+            SciError::FormatError(message) => ("format error", message.as_deref()),
+            SciError::GenericError(message) => ("error", message.as_deref()),
+            SciError::OperationError(message) => ("operation error", message.as_deref()),
+            SciError::OutOfRangeError(message) => ("out of range error", message.as_deref()),
+            SciError::ParseError(error) => return write!(f, "parse error: {}", error),
+            SciError::PrecisionError(message) => ("precision error", message.as_deref()),
+            SciError::ScaleError(message) => ("scale error", message.as_deref()),
+            SciError::Unexpected(message) => ("unexpected error", Some(message.as_str()))
+        };
+
+        match message {
+            Some(message) => write!(f, "{}: {}", kind, message),
+            None => write!(f, "{}", kind)
+        }
+    }
+}
+
+impl std::error::Error for SciError {}
+
+impl std::error::Error for SciParseError {}
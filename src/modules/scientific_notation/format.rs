@@ -0,0 +1,235 @@
+use std::fmt::{Alignment, Display, Formatter, LowerExp, UpperExp};
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::big::BigSciNote;
+use crate::modules::scientific_notation::notation::Notation;
+use crate::modules::scientific_notation::rounding::RoundingMode;
+use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::modules::scientific_notation::si;
+use crate::modules::scientific_notation::wide::WideSciNote;
+
+/// Maximum amount of significant figures that can be displayed, the precision of a Decimal
+pub(super) const MAX_SIGNIFICANT_FIGURES: usize = 28;
+
+/// Rounding applied to the displayed coefficient when none is set
+pub(super) const DISPLAY_ROUNDING: RoundingMode = RoundingMode::HalfTowardZero;
+
+/// Formatting settings carried by each value. Like the display decimals, they don't take part in
+/// comparisons, and operation results keep the ones of the first operand that has them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct FormatOptions {
+    /// Significant figures of the displayed coefficient, trailing zeros included
+    pub(crate) significant_figures: Option<usize>,
+    /// Rounding of the displayed coefficient
+    pub(crate) rounding: Option<RoundingMode>,
+    /// Notation used to display the value
+    pub(crate) notation: Option<Notation>
+}
+
+impl FormatOptions {
+    /// Keep these settings, completing the missing ones with the `other` ones
+    pub(super) fn or(self, other: Self) -> Self {
+        Self {
+            significant_figures: self.significant_figures.or(other.significant_figures),
+            rounding: self.rounding.or(other.rounding),
+            notation: self.notation.or(other.notation)
+        }
+    }
+}
+
+/// Implement the display settings builders of a type, limiting the significant figures to the
+/// `$max_figures` its coefficient holds
+macro_rules! impl_format_setters {
+    ($type:ident, $max_figures:expr) => {
+        impl $type {
+            /// Display the coefficient rounded to `decimals` decimal places. Replaces the significant figures
+            pub fn display_decimals(mut self, decimals: usize) -> Self {
+                self.display_decimals = Some(decimals);
+                self.format.significant_figures = None;
+                self
+            }
+
+            /// Display the coefficient rounded to `figures` significant figures, keeping trailing zeros, so
+            /// `5x10^3` with three figures is displayed as `5.00x10^3`. At least one figure is displayed,
+            /// and no more than the coefficient holds, 28 for a Decimal. Replaces the display decimals
+            pub fn significant_figures(mut self, figures: usize) -> Self {
+                self.format.significant_figures = Some(figures.clamp(1, $max_figures));
+                self.display_decimals = None;
+                self
+            }
+
+            /// Display the value in the `notation`. The default is [Notation::Scientific]
+            pub fn notation(mut self, notation: Notation) -> Self {
+                self.format.notation = Some(notation);
+                self
+            }
+
+            /// Round the displayed coefficient with `mode`. The default is [RoundingMode::HalfTowardZero]
+            pub fn rounding(mut self, mode: RoundingMode) -> Self {
+                self.format.rounding = Some(mode);
+                self
+            }
+        }
+    };
+}
+
+impl_format_setters!(SciNote, MAX_SIGNIFICANT_FIGURES);
+impl_format_setters!(BigSciNote, usize::MAX);
+impl_format_setters!(WideSciNote, MAX_SIGNIFICANT_FIGURES);
+
+impl SciNote {
+    /// Coefficient and exponent as they're displayed: rounded to the `precision` decimals, or to
+    /// the significant figures or display decimals if there's no precision, and with the exponent
+    /// adapted if the rounding carried into a new integer digit, `9.996x10^2` with two decimals is
+    /// displayed as `1.00x10^3`
+    pub(super) fn display_parts(&self, precision: Option<usize>) -> (Decimal, i32) {
+        //  The coefficient has one integer digit, the other significant figures are decimals. Like
+        // floats, a precision and significant figures keep trailing zeros
+        let (decimals, keep_trailing_zeros) = match (precision, self.format.significant_figures) {
+            (Some(precision), _) => (precision as u32, true),
+            (None, Some(figures)) => (figures.saturating_sub(1) as u32, true),
+            (None, None) => (self.display_decimals.unwrap_or(2) as u32, false)
+        };
+        let rounding = self.format.rounding.unwrap_or(DISPLAY_ROUNDING);
+
+        //  Significant figures are counted on the normalized coefficient in both notations, the
+        // engineering one only moves the point afterwards. Decimals are counted on the displayed one
+        let engineering = matches!(self.format.notation, Some(Notation::Engineering | Notation::SiPrefix));
+        let figures_only = precision.is_none() && self.format.significant_figures.is_some();
+
+        //  The exponent is only displayed, it may go past the i16 range
+        let (mut coefficient, exponent) = if engineering && !figures_only {
+            self.round_engineering(decimals, rounding)
+        } else {
+            self.round_unbounded(decimals, rounding)
+                .unwrap_or((self.coefficient, i32::from(self.exponent)))
+        };
+
+        if keep_trailing_zeros {
+            coefficient.rescale(decimals);
+        }
+
+        if engineering && figures_only {
+            return Self::engineering_parts(coefficient, exponent)
+        }
+        (coefficient, exponent)
+    }
+
+    /// Coefficient and exponent of the E-notation. Like floats, the whole coefficient is written
+    /// unless there's a precision, or the display decimals or significant figures are set
+    pub(super) fn exp_parts(&self, precision: Option<usize>) -> (Decimal, i32) {
+        if precision.is_none() && self.display_decimals.is_none() && self.format.significant_figures.is_none() {
+            return match self.format.notation {
+                Some(Notation::Engineering | Notation::SiPrefix) => self.to_engineering(),
+                _ => (self.coefficient, i32::from(self.exponent))
+            }
+        }
+        self.display_parts(precision)
+    }
+}
+
+/// Text of a displayed coefficient. A Decimal holds up to 28 decimals, so a bigger `precision` is
+/// completed with zeros, like floats do: `{:.40}` displays 40 decimals
+pub(super) fn coefficient_text(coefficient: Decimal, precision: Option<usize>) -> String {
+    let scale = coefficient.scale() as usize;
+    match precision {
+        Some(precision) if precision > scale => {
+            let point = if scale == 0 { "." } else { "" };
+            format!("{}{}{}", coefficient, point, "0".repeat(precision - scale))
+        }
+        _ => coefficient.to_string()
+    }
+}
+
+/// Write a coefficient followed by the `rest` of the notation, honoring the width, fill, alignment
+/// and `+` flags of the formatter. Without alignment the notation is right aligned like numbers,
+/// and the `0` flag pads with zeros between the sign and the digits
+pub(super) fn pad_notation(f: &mut Formatter<'_>, coefficient: &str, rest: &str) -> std::fmt::Result {
+    let (sign, magnitude) = match coefficient.strip_prefix('-') {
+        Some(magnitude) => ("-", magnitude),
+        None if f.sign_plus() => ("+", coefficient),
+        None => ("", coefficient)
+    };
+    let unsigned = format!("{}{}", magnitude, rest);
+    let padding = f.width()
+        .unwrap_or(0)
+        .saturating_sub(sign.len() + unsigned.chars().count());
+
+    if f.sign_aware_zero_pad() {
+        return write!(f, "{}{}{}", sign, "0".repeat(padding), unsigned)
+    }
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0)
+    };
+    let fill = f.fill().to_string();
+
+    write!(f, "{}{}{}{}", fill.repeat(before), sign, unsigned, fill.repeat(after))
+}
+
+/// Write a value in its notation, or with its SI prefix in the SI notation. Out of the prefixes
+/// range, the value is written in scientific notation, with the parts computed by `scientific`
+pub(super) fn write_notation(
+    f: &mut Formatter<'_>,
+    (coefficient, exponent): (String, i128),
+    si_prefix: bool,
+    scientific: impl FnOnce() -> (String, i128)
+) -> std::fmt::Result {
+    if si_prefix {
+        return match i32::try_from(exponent).ok().and_then(si::prefix_of) {
+            Some("") => pad_notation(f, &coefficient, ""),
+            Some(prefix) => pad_notation(f, &coefficient, &format!(" {}", prefix)),
+            None => {
+                //  Out of the prefixes range, fall back to scientific notation
+                let (coefficient, exponent) = scientific();
+                pad_notation(f, &coefficient, &format!("x10^{}", exponent))
+            }
+        }
+    }
+
+    pad_notation(f, &coefficient, &format!("x10^{}", exponent))
+}
+
+/// Implement `Display`, `LowerExp` and `UpperExp` for a type with a Decimal coefficient, on top of
+/// its `display_parts` and `exp_parts`
+macro_rules! impl_display {
+    ($type:ident) => {
+        /// Precision, width, fill, alignment and `+` flags are honored: `{:.4}` displays four decimals
+        /// instead of the display decimals or significant figures, and `{:>20}` pads the whole notation
+        impl Display for $type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                let precision = f.precision();
+                let text = |(coefficient, exponent): (Decimal, _)| {
+                    (coefficient_text(coefficient, precision), i128::from(exponent))
+                };
+
+                write_notation(
+                    f,
+                    text(self.display_parts(precision)),
+                    self.format.notation == Some(Notation::SiPrefix),
+                    || text(self.notation(Notation::Scientific).display_parts(precision))
+                )
+            }
+        }
+
+        /// E-notation with a lowercase marker, `6.022e23`, honoring the same flags as `Display`
+        impl LowerExp for $type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                let (coefficient, exponent) = self.exp_parts(f.precision());
+                pad_notation(f, &coefficient_text(coefficient, f.precision()), &format!("e{}", exponent))
+            }
+        }
+
+        /// E-notation with an uppercase marker, `6.022E23`, honoring the same flags as `Display`
+        impl UpperExp for $type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                let (coefficient, exponent) = self.exp_parts(f.precision());
+                pad_notation(f, &coefficient_text(coefficient, f.precision()), &format!("E{}", exponent))
+            }
+        }
+    };
+}
+
+impl_display!(SciNote);
+impl_display!(WideSciNote);
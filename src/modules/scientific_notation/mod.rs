@@ -1,5 +1,18 @@
 #[cfg(test)]
 mod tests;
 pub (in super::super) mod sci_not;
+pub (in super::super) mod builder;
 pub (in super::super) mod error;
-
+pub (in super::super) mod rounding;
+pub (in super::super) mod notation;
+pub (in super::super) mod big;
+pub (in super::super) mod wide;
+pub (in super::super) mod context;
+mod ops;
+mod overflow;
+mod cmp;
+mod parse;
+mod convert;
+mod format;
+mod si;
+mod render;
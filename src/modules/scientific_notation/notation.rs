@@ -0,0 +1,56 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use crate::modules::scientific_notation::rounding::RoundingMode;
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+/// How a value is displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// One integer digit in the coefficient, `1 <= |coefficient| < 10`: `4.7x10^3`
+    Scientific,
+    /// An exponent that's a multiple of three, and up to three integer digits in the coefficient,
+    /// `1 <= |coefficient| < 1000`: `47x10^3`. It maps to the SI prefixes, kilo, mega, micro...
+    Engineering,
+    /// Engineering notation with the SI prefix of the exponent instead of the power of ten:
+    /// `47 k`, `220 n`. Values out of the prefixes range, from quecto to quetta, are displayed in
+    /// scientific notation
+    SiPrefix
+}
+
+impl SciNote {
+    /// Coefficient and exponent in engineering notation: the exponent is the closest multiple of
+    /// three below the normalized one, and the coefficient is in `[1, 1000)`, so `4.7x10^4` is
+    /// `(47, 3)`. Zero is `(0, 0)`.
+    ///
+    /// The exponent is wider than an i16 because the closest multiple of three below the minimum
+    /// exponent is out of its range
+    pub fn to_engineering(&self) -> (Decimal, i32) {
+        Self::engineering_parts(self.coefficient, i32::from(self.exponent))
+    }
+
+    /// Move the point of a normalized coefficient to make the exponent a multiple of three. The
+    /// decimals moved to the integer part are dropped from the scale, 1.20x10^5 is 120x10^3
+    pub(super) fn engineering_parts(coefficient: Decimal, exponent: i32) -> (Decimal, i32) {
+        let shift = exponent.rem_euclid(3) as u32;
+
+        let mut shifted = coefficient * Decimal::from(10u32.pow(shift));
+        shifted.rescale(coefficient.scale().saturating_sub(shift));
+
+        (shifted, exponent - shift as i32)
+    }
+
+    /// Round the engineering coefficient to `decimals` places, moving to the next multiple of three
+    /// if the rounding carried into a fourth integer digit, 999.996 is rounded to 1.00x10^3
+    pub(super) fn round_engineering(&self, decimals: u32, mode: RoundingMode) -> (Decimal, i32) {
+        let strategy = RoundingStrategy::from(mode);
+        let (coefficient, exponent) = self.to_engineering();
+        let rounded = coefficient.round_dp_with_strategy(decimals, strategy);
+
+        if rounded.abs() < Decimal::ONE_THOUSAND {
+            return (rounded, exponent)
+        }
+
+        let mut carried = (rounded / Decimal::ONE_THOUSAND).round_dp_with_strategy(decimals, strategy);
+        carried.rescale(decimals);
+        (carried, exponent + 3)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::modules::scientific_notation::big::BigSciNote;
+use crate::modules::scientific_notation::error::SciResult;
+use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::modules::scientific_notation::wide::WideSciNote;
+
+/// Implement an arithmetic operator for all owned and borrowed combinations, plus its assigning
+/// version, on top of the fallible method with the same name. The operator panics if the method fails
+macro_rules! impl_operator {
+    ($type:ident, $operator:ident, $method:ident, $assign_operator:ident, $assign_method:ident, $operation:literal) => {
+        impl std::ops::$operator<&$type> for &$type {
+            type Output = $type;
+            fn $method(self, rhs: &$type) -> Self::Output {
+                $type::$method(self, rhs)
+                    .unwrap_or_else(|error| panic!("attempt to {} failed: {:?}", $operation, error))
+            }
+        }
+
+        impl std::ops::$operator<$type> for $type {
+            type Output = $type;
+            fn $method(self, rhs: $type) -> Self::Output {
+                std::ops::$operator::$method(&self, &rhs)
+            }
+        }
+
+        impl std::ops::$operator<&$type> for $type {
+            type Output = $type;
+            fn $method(self, rhs: &$type) -> Self::Output {
+                std::ops::$operator::$method(&self, rhs)
+            }
+        }
+
+        impl std::ops::$operator<$type> for &$type {
+            type Output = $type;
+            fn $method(self, rhs: $type) -> Self::Output {
+                std::ops::$operator::$method(self, &rhs)
+            }
+        }
+
+        impl std::ops::$assign_operator<&$type> for $type {
+            fn $assign_method(&mut self, rhs: &$type) {
+                *self = std::ops::$operator::$method(&*self, rhs);
+            }
+        }
+
+        impl std::ops::$assign_operator<$type> for $type {
+            fn $assign_method(&mut self, rhs: $type) {
+                *self = std::ops::$operator::$method(&*self, &rhs);
+            }
+        }
+    };
+}
+
+/// Implement the fallible subtraction of a type on top of its addition and negation
+macro_rules! impl_subtraction {
+    ($type:ident) => {
+        impl $type {
+            pub fn sub(&self, input2: &Self) -> SciResult<Self> {
+                //  Subtracting is adding the opposite of the second operand
+                self.add(&-input2)
+            }
+        }
+    };
+}
+
+impl_subtraction!(SciNote);
+impl_subtraction!(BigSciNote);
+impl_subtraction!(WideSciNote);
+
+impl_operator!(SciNote, Add, add, AddAssign, add_assign, "add");
+impl_operator!(SciNote, Sub, sub, SubAssign, sub_assign, "subtract");
+impl_operator!(SciNote, Mul, mul, MulAssign, mul_assign, "multiply");
+impl_operator!(SciNote, Div, div, DivAssign, div_assign, "divide");
+
+impl_operator!(BigSciNote, Add, add, AddAssign, add_assign, "add");
+impl_operator!(BigSciNote, Sub, sub, SubAssign, sub_assign, "subtract");
+impl_operator!(BigSciNote, Mul, mul, MulAssign, mul_assign, "multiply");
+impl_operator!(BigSciNote, Div, div, DivAssign, div_assign, "divide");
+
+impl_operator!(WideSciNote, Add, add, AddAssign, add_assign, "add");
+impl_operator!(WideSciNote, Sub, sub, SubAssign, sub_assign, "subtract");
+impl_operator!(WideSciNote, Mul, mul, MulAssign, mul_assign, "multiply");
+impl_operator!(WideSciNote, Div, div, DivAssign, div_assign, "divide");
+
+impl std::ops::Neg for &SciNote {
+    type Output = SciNote;
+    fn neg(self) -> Self::Output {
+        //  Zero has no sign, keep it as it is
+        if self.coefficient.is_zero() {
+            return *self
+        }
+
+        SciNote {
+            coefficient: -self.coefficient,
+            ..*self
+        }
+    }
+}
+
+impl std::ops::Neg for SciNote {
+    type Output = SciNote;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl std::ops::Neg for &BigSciNote {
+    type Output = BigSciNote;
+    fn neg(self) -> Self::Output {
+        BigSciNote {
+            mantissa: -&self.mantissa,
+            exponent: self.exponent,
+            display_decimals: self.display_decimals,
+            format: self.format
+        }
+    }
+}
+
+impl std::ops::Neg for BigSciNote {
+    type Output = BigSciNote;
+    fn neg(mut self) -> Self::Output {
+        self.mantissa = -self.mantissa;
+        self
+    }
+}
+
+impl std::ops::Neg for &WideSciNote {
+    type Output = WideSciNote;
+    fn neg(self) -> Self::Output {
+        //  Zero has no sign, keep it as it is
+        if self.coefficient.is_zero() {
+            return *self
+        }
+
+        WideSciNote {
+            coefficient: -self.coefficient,
+            ..*self
+        }
+    }
+}
+
+impl std::ops::Neg for WideSciNote {
+    type Output = WideSciNote;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
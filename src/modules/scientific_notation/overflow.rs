@@ -0,0 +1,266 @@
+use num_bigint::{BigInt, Sign};
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::big::{self, BigSciNote};
+use crate::modules::scientific_notation::error::SciResult;
+use crate::modules::scientific_notation::format::FormatOptions;
+use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::modules::scientific_notation::wide::WideSciNote;
+
+/// Implement the checked arithmetic of a type on top of its fallible operations
+macro_rules! impl_checked {
+    ($type:ident) => {
+        impl $type {
+            /// Checked addition. Returns `None` if the result can't be represented
+            pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                self.add(rhs).ok()
+            }
+
+            /// Checked subtraction. Returns `None` if the result can't be represented
+            pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                self.sub(rhs).ok()
+            }
+
+            /// Checked multiplication. Returns `None` if the result can't be represented
+            pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                self.mul(rhs).ok()
+            }
+
+            /// Checked division. Returns `None` if `rhs` is zero or the result can't be represented
+            pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                self.div(rhs).ok()
+            }
+        }
+    };
+}
+
+//  Overflow aware arithmetic, mirroring the integer APIs in std. An operation overflows when the
+// exponent of its result doesn't fit in an i16, or in an i64 for WideSciNote
+impl_checked!(SciNote);
+impl_checked!(BigSciNote);
+impl_checked!(WideSciNote);
+
+/// Overflowing arithmetic like [SciNote]'s. There's no saturating arithmetic: the coefficient has
+/// no digits limit, so there's no largest value to clamp to
+impl BigSciNote {
+    /// Calculates `self + rhs`. Returns the result with its exponent wrapped around the i16 range,
+    /// and a flag telling if an overflow happened
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        Self::wrap(
+            Ok(self.add_unbounded(rhs)),
+            self.display_decimals.or(rhs.display_decimals),
+            self.format.or(rhs.format),
+            "add"
+        )
+    }
+
+    /// Calculates `self - rhs`. Returns the result with its exponent wrapped around the i16 range,
+    /// and a flag telling if an overflow happened
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        self.overflowing_add(&-rhs)
+    }
+
+    /// Calculates `self * rhs`. Returns the result with its exponent wrapped around the i16 range,
+    /// and a flag telling if an overflow happened
+    pub fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
+        Self::wrap(
+            Ok(self.mul_unbounded(rhs)),
+            self.display_decimals.or(rhs.display_decimals),
+            self.format.or(rhs.format),
+            "multiply"
+        )
+    }
+
+    /// Calculates `self / rhs`, with the precision of [BigSciNote::div]. Returns the result with
+    /// its exponent wrapped around the i16 range, and a flag telling if an overflow happened
+    ///
+    /// ## Panics
+    /// Like integer overflowing division, it panics if `rhs` is zero
+    pub fn overflowing_div(&self, rhs: &Self) -> (Self, bool) {
+        Self::wrap(
+            self.div_rounded(rhs, Self::DIVISION_DIGITS, SciNote::DIVISION_ROUNDING),
+            self.display_decimals.or(rhs.display_decimals),
+            self.format.or(rhs.format),
+            "divide"
+        )
+    }
+
+    fn wrap(
+        result: SciResult<(BigInt, i64)>,
+        display_decimals: Option<usize>,
+        format: FormatOptions,
+        operation: &str
+    ) -> (Self, bool) {
+        let (mantissa, power) = result
+            .unwrap_or_else(|error| panic!("attempt to {} failed: {:?}", operation, error));
+
+        //  Truncating to i16 wraps the exponent around, the same way integers do
+        let is_zero = mantissa.sign() == Sign::NoSign;
+        let exponent = power + i64::from(big::digit_count(mantissa.magnitude())) - 1;
+        let wrapped_exponent = exponent as i16;
+        let overflowed = !is_zero && i64::from(wrapped_exponent) != exponent;
+
+        let wrapped = Self {
+            mantissa,
+            exponent: if is_zero { 0 } else { wrapped_exponent },
+            display_decimals,
+            format
+        };
+
+        (wrapped, overflowed)
+    }
+}
+
+/// Implement the saturating and overflowing arithmetic of a type with a Decimal coefficient, on
+/// top of its unbounded operations, for the `$exponent` range of its exponent
+macro_rules! impl_decimal_overflow {
+    ($type:ident, $exponent:ident) => {
+        impl $type {
+            #[doc = concat!("Saturating addition. Clamps to [", stringify!($type), "::MAX] or [", stringify!($type), "::MIN] on")]
+            /// overflow, and to zero when the result is too close to zero to be represented
+            pub fn saturating_add(&self, rhs: &Self) -> Self {
+                Self::saturate(
+                    self.add_unbounded(rhs),
+                    self.display_decimals.or(rhs.display_decimals),
+                    self.format.or(rhs.format),
+                    "add"
+                )
+            }
+
+            #[doc = concat!("Saturating subtraction. Clamps to [", stringify!($type), "::MAX] or [", stringify!($type), "::MIN] on")]
+            /// overflow, and to zero when the result is too close to zero to be represented
+            pub fn saturating_sub(&self, rhs: &Self) -> Self {
+                self.saturating_add(&-rhs)
+            }
+
+            #[doc = concat!("Saturating multiplication. Clamps to [", stringify!($type), "::MAX] or [", stringify!($type), "::MIN] on")]
+            /// overflow, and to zero when the result is too close to zero to be represented
+            pub fn saturating_mul(&self, rhs: &Self) -> Self {
+                Self::saturate(
+                    self.mul_unbounded(rhs),
+                    self.display_decimals.or(rhs.display_decimals),
+                    self.format.or(rhs.format),
+                    "multiply"
+                )
+            }
+
+            #[doc = concat!("Saturating division. Clamps to [", stringify!($type), "::MAX] or [", stringify!($type), "::MIN] on")]
+            /// overflow, and to zero when the result is too close to zero to be represented
+            ///
+            /// ## Panics
+            /// Like integer saturating division, it panics if `rhs` is zero
+            pub fn saturating_div(&self, rhs: &Self) -> Self {
+                Self::saturate(
+                    self.div_unbounded(rhs, SciNote::MAX_DIVISION_DIGITS, SciNote::DIVISION_ROUNDING),
+                    self.display_decimals.or(rhs.display_decimals),
+                    self.format.or(rhs.format),
+                    "divide"
+                )
+            }
+
+            #[doc = concat!("Calculates `self + rhs`. Returns the result with its exponent wrapped around the ", stringify!($exponent))]
+            /// range, and a flag telling if an overflow happened
+            pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+                Self::wrap(
+                    self.add_unbounded(rhs),
+                    self.display_decimals.or(rhs.display_decimals),
+                    self.format.or(rhs.format),
+                    "add"
+                )
+            }
+
+            #[doc = concat!("Calculates `self - rhs`. Returns the result with its exponent wrapped around the ", stringify!($exponent))]
+            /// range, and a flag telling if an overflow happened
+            pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+                self.overflowing_add(&-rhs)
+            }
+
+            #[doc = concat!("Calculates `self * rhs`. Returns the result with its exponent wrapped around the ", stringify!($exponent))]
+            /// range, and a flag telling if an overflow happened
+            pub fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
+                Self::wrap(
+                    self.mul_unbounded(rhs),
+                    self.display_decimals.or(rhs.display_decimals),
+                    self.format.or(rhs.format),
+                    "multiply"
+                )
+            }
+
+            #[doc = concat!("Calculates `self / rhs`. Returns the result with its exponent wrapped around the ", stringify!($exponent))]
+            /// range, and a flag telling if an overflow happened
+            ///
+            /// ## Panics
+            /// Like integer overflowing division, it panics if `rhs` is zero
+            pub fn overflowing_div(&self, rhs: &Self) -> (Self, bool) {
+                Self::wrap(
+                    self.div_unbounded(rhs, SciNote::MAX_DIVISION_DIGITS, SciNote::DIVISION_ROUNDING),
+                    self.display_decimals.or(rhs.display_decimals),
+                    self.format.or(rhs.format),
+                    "divide"
+                )
+            }
+
+            fn saturate<E: Into<i128>>(
+                result: SciResult<(Decimal, E)>,
+                display_decimals: Option<usize>,
+                format: FormatOptions,
+                operation: &str
+            ) -> Self {
+                let (coefficient, exponent) = result
+                    .unwrap_or_else(|error| panic!("attempt to {} failed: {:?}", operation, error));
+                let exponent = exponent.into();
+
+                if coefficient.is_zero() || exponent < i128::from($exponent::MIN) {
+                    //  Too small to be represented, the closest value is zero
+                    Self {
+                        coefficient: Decimal::ZERO,
+                        exponent: 0,
+                        display_decimals,
+                        format
+                    }
+                } else if exponent > i128::from($exponent::MAX) {
+                    //  Too big to be represented, clamp to the closest bound
+                    let bound = if coefficient.is_sign_negative() { Self::MIN } else { Self::MAX };
+                    Self {
+                        display_decimals,
+                        format,
+                        ..bound
+                    }
+                } else {
+                    Self {
+                        coefficient,
+                        exponent: exponent as $exponent,
+                        display_decimals,
+                        format
+                    }
+                }
+            }
+
+            fn wrap<E: Into<i128>>(
+                result: SciResult<(Decimal, E)>,
+                display_decimals: Option<usize>,
+                format: FormatOptions,
+                operation: &str
+            ) -> (Self, bool) {
+                let (coefficient, exponent) = result
+                    .unwrap_or_else(|error| panic!("attempt to {} failed: {:?}", operation, error));
+                let exponent = exponent.into();
+
+                //  Truncating the exponent wraps it around, the same way integers do
+                let wrapped_exponent = exponent as $exponent;
+                let overflowed = !coefficient.is_zero() && i128::from(wrapped_exponent) != exponent;
+
+                let wrapped = Self {
+                    coefficient,
+                    exponent: if coefficient.is_zero() { 0 } else { wrapped_exponent },
+                    display_decimals,
+                    format
+                };
+
+                (wrapped, overflowed)
+            }
+        }
+    };
+}
+
+impl_decimal_overflow!(SciNote, i16);
+impl_decimal_overflow!(WideSciNote, i64);
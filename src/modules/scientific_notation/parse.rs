@@ -0,0 +1,319 @@
+use std::num::IntErrorKind;
+use std::str::FromStr;
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::big::BigSciNote;
+use crate::modules::scientific_notation::error::{ParsePart, SciError, SciParseError, SciResult};
+use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::modules::scientific_notation::wide::WideSciNote;
+
+/// Signs accepted between the coefficient and the power of ten: `6.022x10^23`, `6.022*10^23`,
+/// `6.022×10^23` and `6.022·10²³`
+const MULTIPLICATION_SIGNS: [char; 4] = ['x', '*', '×', '·'];
+
+/// Letters accepted as exponent markers: `6.022e23`, `6.022E+23` and Fortran's `1.0D+05`
+const EXPONENT_MARKERS: [char; 4] = ['e', 'E', 'd', 'D'];
+
+/// A fragment of the input, and the byte offset where it starts
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Fragment<'a> {
+    pub(super) text: &'a str,
+    pub(super) position: usize
+}
+
+/// How the exponent digits are written
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum ExponentStyle {
+    Ascii,
+    Superscript
+}
+
+/// Parse the input into its coefficient and exponent, without normalizing them
+pub(super) fn parse_notation(input: &str) -> SciResult<(Decimal, i32)> {
+    parse_notation_with(input, parse_coefficient, Decimal::is_zero, |coefficient, exponent| {
+        Ok((coefficient, exponent_in_range(exponent)?))
+    })
+}
+
+/// Parse the input like [parse_notation], with another coefficient parser, so every syntax and
+/// error is shared by the coefficient types. The value is made by `build` from the coefficient and
+/// the exponent, and an `ExponentError` it returns is reported at the exponent of the input
+pub(super) fn parse_notation_with<C, T>(
+    input: &str,
+    parse_coefficient: impl Fn(Fragment) -> SciResult<C>,
+    is_zero: impl Fn(&C) -> bool,
+    build: impl FnOnce(C, i128) -> SciResult<T>
+) -> SciResult<T> {
+    let (coefficient_fragment, exponent_fragment) = split_notation(input)?;
+
+    let coefficient = parse_coefficient(coefficient_fragment)?;
+
+    //  A plain zero is the only number that doesn't need an exponent
+    let Some((exponent_fragment, style)) = exponent_fragment else {
+        if is_zero(&coefficient) {
+            return build(coefficient, 0)
+        }
+        let end = coefficient_fragment.position + coefficient_fragment.text.len();
+        return Err(SciParseError::new(
+            ParsePart::Separator,
+            end,
+            "",
+            "missing exponent, expected a notation like 6.022x10^23 or 6.022e23"
+        ).into())
+    };
+
+    //  A zero is the canonical zero whatever its exponent is, even one out of range
+    let exponent = parse_exponent(exponent_fragment, style)?;
+    let exponent = if is_zero(&coefficient) { 0 } else { exponent };
+
+    build(coefficient, exponent).map_err(|error| match error {
+        SciError::ExponentError(_) => out_of_range(exponent_fragment),
+        error => error
+    })
+}
+
+/// Convert a parsed exponent into a narrower integer, failing with an `ExponentError` if it's out
+/// of its range
+pub(super) fn exponent_in_range<E: TryFrom<i128>>(exponent: i128) -> SciResult<E> {
+    E::try_from(exponent).map_err(|_| SciError::ExponentError(Some(format!("Exponent {} out of range", exponent))))
+}
+
+/// Parse error of a value whose exponent, or the one it's normalized to, is out of range
+fn out_of_range(fragment: Fragment) -> SciError {
+    SciParseError::new(ParsePart::Exponent, fragment.position, fragment.text, "exponent out of range").into()
+}
+
+/// Count the significant figures written in the coefficient of a valid input
+pub(super) fn count_significant_figures(input: &str) -> SciResult<usize> {
+    parse_notation(input)?;
+    let (coefficient, _) = split_notation(input)?;
+
+    let unsigned = coefficient.text.trim_start_matches(['+', '-']);
+    let (integer, decimals) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let point_found = unsigned.contains('.');
+
+    let digits = format!("{}{}", integer, decimals);
+    let significant = digits.trim_start_matches('0');
+
+    let figures = if significant.is_empty() {
+        //  A zero is as precise as the zeros written after the point
+        decimals.len().max(1)
+    } else if point_found {
+        significant.len()
+    } else {
+        //  Without a point, the trailing zeros of an integer may be placeholders
+        significant.trim_end_matches('0').len()
+    };
+
+    Ok(figures)
+}
+
+/// Split the input into its coefficient and exponent fragments. The exponent is None if the input
+/// doesn't contain any separator
+pub(super) fn split_notation(input: &str) -> SciResult<(Fragment<'_>, Option<(Fragment<'_>, ExponentStyle)>)> {
+    let start = input.len() - input.trim_start().len();
+    let trimmed = input.trim();
+    let fragment = |from: usize, to: usize| Fragment {
+        text: input[from..to].trim(),
+        position: from + (input[from..to].len() - input[from..to].trim_start().len())
+    };
+
+    //  First, look for a multiplication sign followed by a power of ten
+    for (index, sign) in trimmed.char_indices().filter(|(_, character)| MULTIPLICATION_SIGNS.contains(character)) {
+        let sign_position = start + index;
+        let after_sign = &trimmed[index + sign.len_utf8()..];
+        let Some(power) = after_sign.trim_start().strip_prefix("10") else {
+            continue
+        };
+        let coefficient = fragment(start, sign_position);
+        let power_position = start + trimmed.len() - power.len();
+
+        //  Either 10^EE or 10 followed by a superscript exponent
+        if let Some(exponent) = power.strip_prefix('^') {
+            let exponent = Fragment { text: exponent, position: power_position + 1 };
+            return Ok((coefficient, Some((exponent, ExponentStyle::Ascii))))
+        }
+        if power.starts_with(|character| from_superscript(character).is_some()) {
+            let exponent = Fragment { text: power, position: power_position };
+            return Ok((coefficient, Some((exponent, ExponentStyle::Superscript))))
+        }
+        let separator = &input[sign_position..power_position];
+        return Err(SciParseError::new(
+            ParsePart::Separator,
+            power_position,
+            power,
+            format!("expected '^' or a superscript exponent after '{}'", separator)
+        ).into())
+    }
+
+    //  Otherwise, look for an E-notation marker
+    if let Some((index, marker)) = trimmed.char_indices().find(|(_, character)| EXPONENT_MARKERS.contains(character)) {
+        let marker_position = start + index;
+        let exponent = Fragment {
+            text: &trimmed[index + marker.len_utf8()..],
+            position: marker_position + marker.len_utf8()
+        };
+        return Ok((fragment(start, marker_position), Some((exponent, ExponentStyle::Ascii))))
+    }
+
+    Ok((fragment(start, start + trimmed.len()), None))
+}
+
+pub(super) fn parse_coefficient(coefficient: Fragment) -> SciResult<Decimal> {
+    if coefficient.text.is_empty() {
+        return Err(SciParseError::new(ParsePart::Coefficient, coefficient.position, "", "missing coefficient").into())
+    }
+
+    //  Decimal's parser is lenient, it accepts underscores between digits, so every character is
+    // checked first and the parser only sees plain numbers
+    check_number_characters(coefficient, ParsePart::Coefficient, true)?;
+
+    //  With valid characters, the number may still be too big for a Decimal
+    coefficient.text
+        .parse::<Decimal>()
+        .map_err(|_| SciParseError::new(
+            ParsePart::Coefficient,
+            coefficient.position,
+            coefficient.text,
+            "too many digits for a Decimal"
+        ).into())
+}
+
+/// Parse the exponent digits. Exponents past the i128 range saturate to its bounds, they're out of
+/// the range of every value, and only rejected by its `build`, so zeros may have any exponent
+fn parse_exponent(exponent: Fragment, style: ExponentStyle) -> SciResult<i128> {
+    if exponent.text.is_empty() {
+        return Err(SciParseError::new(ParsePart::Exponent, exponent.position, "", "missing exponent").into())
+    }
+
+    //  Translate superscripts into ASCII, so both styles are parsed the same way
+    let ascii = match style {
+        ExponentStyle::Ascii => exponent.text.to_string(),
+        ExponentStyle::Superscript => {
+            let mut ascii = String::with_capacity(exponent.text.len());
+            for (offset, character) in exponent.text.char_indices() {
+                let Some(translated) = from_superscript(character) else {
+                    return Err(SciParseError::new(
+                        ParsePart::Exponent,
+                        exponent.position + offset,
+                        &exponent.text[offset..offset + character.len_utf8()],
+                        format!("unexpected character '{}'", character)
+                    ).into())
+                };
+                ascii.push(translated);
+            }
+            ascii
+        }
+    };
+
+    ascii
+        .parse::<i128>()
+        .or_else(|error| match error.kind() {
+            IntErrorKind::PosOverflow => Ok(i128::MAX),
+            IntErrorKind::NegOverflow => Ok(i128::MIN),
+            //  Point to the first character that can't be part of an integer, or to the whole exponent
+            // if all of them are valid but the number isn't
+            _ => Err(check_number_characters(exponent, ParsePart::Exponent, false)
+                .err()
+                .filter(|_| style == ExponentStyle::Ascii)
+                .unwrap_or_else(|| SciParseError::new(
+                    ParsePart::Exponent,
+                    exponent.position,
+                    exponent.text,
+                    error.to_string()
+                ).into()))
+        })
+}
+
+/// Fail on the first character of the fragment that can't be part of a number, see
+/// [first_invalid_character]. The error only holds the reason, its part and position are apart
+pub(super) fn check_number_characters(fragment: Fragment, part: ParsePart, decimal: bool) -> SciResult<()> {
+    let Some((offset, character)) = first_invalid_character(fragment.text, decimal) else {
+        return Ok(())
+    };
+
+    //  Signs and points are only invalid when there are no digits after them
+    let reason = if matches!(character, '+' | '-' | '.') && !fragment.text.contains(|digit: char| digit.is_ascii_digit()) {
+        "missing digits".to_string()
+    } else {
+        format!("unexpected character '{}'", character)
+    };
+
+    Err(SciParseError::new(
+        part,
+        fragment.position + offset,
+        &fragment.text[offset..offset + character.len_utf8()],
+        reason
+    ).into())
+}
+
+/// Find the first character that can't be part of a number: an optional leading sign followed by
+/// digits, with a single decimal point if `decimal` is true
+fn first_invalid_character(text: &str, decimal: bool) -> Option<(usize, char)> {
+    let mut point_found = !decimal;
+    let mut digit_found = false;
+
+    for (offset, character) in text.char_indices() {
+        match character {
+            '+' | '-' if offset == 0 => {},
+            '0'..='9' => digit_found = true,
+            '.' if !point_found => point_found = true,
+            _ => return Some((offset, character))
+        }
+    }
+
+    //  A sign alone is not a number
+    if !digit_found {
+        return text.chars().last().map(|character| (text.len() - character.len_utf8(), character))
+    }
+
+    None
+}
+
+/// Translate a superscript digit or sign into its ASCII version
+fn from_superscript(character: char) -> Option<char> {
+    match character {
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴' => Some('4'),
+        '⁵' => Some('5'),
+        '⁶' => Some('6'),
+        '⁷' => Some('7'),
+        '⁸' => Some('8'),
+        '⁹' => Some('9'),
+        '⁻' => Some('-'),
+        '⁺' => Some('+'),
+        _ => None
+    }
+}
+
+/// Implement `FromStr`, `TryFrom<&str>` and `TryFrom<String>` on top of `parse_from_str`
+macro_rules! impl_from_str {
+    ($type:ident) => {
+        impl FromStr for $type {
+            type Err = SciError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $type::parse_from_str(s)
+            }
+        }
+
+        impl TryFrom<&str> for $type {
+            type Error = SciError;
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                $type::parse_from_str(value)
+            }
+        }
+
+        impl TryFrom<String> for $type {
+            type Error = SciError;
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                $type::parse_from_str(&value)
+            }
+        }
+    };
+}
+
+impl_from_str!(SciNote);
+impl_from_str!(BigSciNote);
+impl_from_str!(WideSciNote);
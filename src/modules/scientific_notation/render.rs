@@ -0,0 +1,124 @@
+use crate::modules::scientific_notation::big::BigSciNote;
+use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::modules::scientific_notation::wide::WideSciNote;
+
+/// Renderers for reports and web pages. All of them round the coefficient like `Display` does,
+/// with the display decimals or significant figures, the rounding mode and the notation
+impl SciNote {
+    /// Render with a multiplication sign and a superscript exponent: `6.022 × 10²³`, `1.6 × 10⁻¹⁹`
+    pub fn to_unicode(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        unicode(&coefficient.to_string(), &exponent.to_string())
+    }
+
+    /// Render as a LaTeX math expression: `6.022 \times 10^{23}`, `1.6 \times 10^{-19}`
+    pub fn to_latex(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        latex(&coefficient.to_string(), &exponent.to_string())
+    }
+
+    /// Render as a number of the LaTeX siunitx package: `\num{6.022e23}`, `\num{1.6e-19}`
+    pub fn to_siunitx(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        siunitx(&coefficient.to_string(), &exponent.to_string())
+    }
+
+    /// Render as a MathML expression. Signs are written as operators, so `-1.6x10^-19` is
+    /// `<math><mo>-</mo><mn>1.6</mn><mo>×</mo><msup><mn>10</mn><mrow><mo>-</mo><mn>19</mn></mrow></msup></math>`
+    pub fn to_mathml(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        mathml(&coefficient.to_string(), &exponent.to_string())
+    }
+}
+
+/// The same renderers as [SciNote], with every digit the display settings keep
+impl BigSciNote {
+    pub fn to_unicode(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        unicode(&coefficient, &exponent.to_string())
+    }
+
+    pub fn to_latex(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        latex(&coefficient, &exponent.to_string())
+    }
+
+    pub fn to_siunitx(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        siunitx(&coefficient, &exponent.to_string())
+    }
+
+    pub fn to_mathml(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        mathml(&coefficient, &exponent.to_string())
+    }
+}
+
+/// The same renderers as [SciNote], with the whole i64 exponent
+impl WideSciNote {
+    pub fn to_unicode(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        unicode(&coefficient.to_string(), &exponent.to_string())
+    }
+
+    pub fn to_latex(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        latex(&coefficient.to_string(), &exponent.to_string())
+    }
+
+    pub fn to_siunitx(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        siunitx(&coefficient.to_string(), &exponent.to_string())
+    }
+
+    pub fn to_mathml(&self) -> String {
+        let (coefficient, exponent) = self.display_parts(None);
+        mathml(&coefficient.to_string(), &exponent.to_string())
+    }
+}
+
+fn unicode(coefficient: &str, exponent: &str) -> String {
+    let superscript = exponent.chars().map(to_superscript).collect::<String>();
+    format!("{} × 10{}", coefficient, superscript)
+}
+
+fn latex(coefficient: &str, exponent: &str) -> String {
+    format!("{} \\times 10^{{{}}}", coefficient, exponent)
+}
+
+fn siunitx(coefficient: &str, exponent: &str) -> String {
+    format!("\\num{{{}e{}}}", coefficient, exponent)
+}
+
+fn mathml(coefficient: &str, exponent: &str) -> String {
+    let signed = |number: &str| match number.strip_prefix('-') {
+        Some(magnitude) => format!("<mo>-</mo><mn>{}</mn>", magnitude),
+        None => format!("<mn>{}</mn>", number)
+    };
+    let exponent = if exponent.starts_with('-') {
+        format!("<mrow>{}</mrow>", signed(exponent))
+    } else {
+        signed(exponent)
+    };
+
+    format!("<math>{}<mo>×</mo><msup><mn>10</mn>{}</msup></math>", signed(coefficient), exponent)
+}
+
+/// Translate an ASCII digit or sign into its superscript version
+fn to_superscript(character: char) -> char {
+    match character {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '-' => '⁻',
+        '+' => '⁺',
+        other => other
+    }
+}
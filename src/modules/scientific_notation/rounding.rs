@@ -0,0 +1,78 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use crate::modules::scientific_notation::error::{SciError, SciResult};
+use crate::modules::scientific_notation::format::MAX_SIGNIFICANT_FIGURES;
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+/// How a coefficient is rounded when it has more digits than the ones kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest digit, and ties to the even one: 2.5 -> 2, 3.5 -> 4
+    HalfEven,
+    /// Round to the nearest digit, and ties away from zero: 2.5 -> 3, -2.5 -> -3
+    HalfAwayFromZero,
+    /// Round to the nearest digit, and ties towards zero: 2.5 -> 2, -2.5 -> -2
+    HalfTowardZero,
+    /// Round away from zero: 2.1 -> 3, -2.1 -> -3
+    AwayFromZero,
+    /// Truncate, rounding towards zero: 2.9 -> 2, -2.9 -> -2
+    TowardZero,
+    /// Round towards positive infinity: 2.1 -> 3, -2.9 -> -2
+    Ceiling,
+    /// Round towards negative infinity: 2.9 -> 2, -2.1 -> -3
+    Floor
+}
+
+impl From<RoundingMode> for RoundingStrategy {
+    fn from(mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::HalfAwayFromZero => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::HalfTowardZero => RoundingStrategy::MidpointTowardZero,
+            RoundingMode::AwayFromZero => RoundingStrategy::AwayFromZero,
+            RoundingMode::TowardZero => RoundingStrategy::ToZero,
+            RoundingMode::Ceiling => RoundingStrategy::ToPositiveInfinity,
+            RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity
+        }
+    }
+}
+
+impl SciNote {
+    /// Round the coefficient to `figures` significant figures. The result is normalized, so a
+    /// rounding that carries into a new integer digit adapts the exponent: `9.99x10^2` rounded up
+    /// to two figures is `1.0x10^3`.
+    ///
+    /// `figures` must be between 1 and 28, otherwise a `SciError::PrecisionError` is returned. A
+    /// carry out of the exponent range returns a `SciError::ExponentError`
+    pub fn round_sig_figs(&self, figures: u32, mode: RoundingMode) -> SciResult<Self> {
+        if figures == 0 || figures as usize > MAX_SIGNIFICANT_FIGURES {
+            return Err(SciError::PrecisionError(Some(format!(
+                "Significant figures must be between 1 and {}", MAX_SIGNIFICANT_FIGURES
+            ))))
+        }
+
+        //  The coefficient has one integer digit, the other figures are decimals
+        self.round_decimals(figures - 1, mode)
+    }
+
+    /// Round the coefficient to `decimals` decimal places. The result is normalized, so a rounding
+    /// that carries into a new integer digit adapts the exponent: `9.99x10^2` rounded up to one
+    /// decimal is `1.0x10^3`.
+    ///
+    /// A carry out of the exponent range returns a `SciError::ExponentError`
+    pub fn round_decimals(&self, decimals: u32, mode: RoundingMode) -> SciResult<Self> {
+        let (coefficient, exponent) = self.round_unbounded(decimals, mode)?;
+        Self::from_unbounded(coefficient, exponent, self.display_decimals, self.format)
+    }
+
+    /// Round the coefficient to `decimals` places and scale it again, returning an exponent that
+    /// may be out of the i16 range
+    pub(super) fn round_unbounded(&self, decimals: u32, mode: RoundingMode) -> SciResult<(Decimal, i32)> {
+        let strategy = RoundingStrategy::from(mode);
+        let coefficient = self.coefficient.round_dp_with_strategy(decimals, strategy);
+
+        //  A carry into a new integer digit leaves an extra trailing zero after scaling, 9.99 is
+        // rounded to 10.0 and scaled to 1.00, rounding again drops it
+        let (coefficient, exponent) = Self::scale_unbounded(coefficient, i32::from(self.exponent))?;
+        Ok((coefficient.round_dp_with_strategy(decimals, strategy), exponent))
+    }
+}
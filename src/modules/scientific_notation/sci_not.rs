@@ -1,141 +1,403 @@
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
-use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::big::{self, BigSciNote};
+use crate::modules::scientific_notation::builder::SciNoteBuilder;
 use crate::modules::scientific_notation::error::{SciError, SciResult};
+use crate::modules::scientific_notation::format::FormatOptions;
+use crate::modules::scientific_notation::parse;
+use crate::modules::scientific_notation::rounding::RoundingMode;
+
+/// Maximum amount of decimal places a Decimal can hold
+const MAX_DECIMAL_PRECISION: i32 = 28;
+
+/// Powers of ten from 10^0 to 10^28, to count the digits of a Decimal mantissa. The largest
+/// mantissa, 2^96 - 1, has 29 digits
+const POWERS_OF_TEN: [u128; 29] = {
+    let mut powers = [1u128; 29];
+    let mut index = 1;
+    while index < powers.len() {
+        powers[index] = powers[index - 1] * 10;
+        index += 1;
+    }
+    powers
+};
 
-#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
+#[derive(Debug, Clone, Copy)]
+/// The coefficient is always normalized to one integer digit, `1 <= |coefficient| < 10`, or it's
+/// exactly zero. Parsing, the builder and every operation scale the coefficient and adapt the exponent.
+///
 /// Maximum parsing allowed as coefficient mantissa: i64, meaning, on creation, up to i64::MAX and i64::MIN
 /// is allowed.
 /// ## Examples:
 /// - Valid positive number
-/// 9.223372036854775807x10^5 -> The mantissa is equivalent to i64::MAX, hence, it can be parsed
+///   9.223372036854775807x10^5 -> The mantissa is equivalent to i64::MAX, hence, it can be parsed
 /// - Valid negative number
-/// -9.223372036854775807x10^5 -> The mantissa is equivalent to i64::MIN, it'll be parsed as well
+///   -9.223372036854775807x10^5 -> The mantissa is equivalent to i64::MIN, it'll be parsed as well
 /// - Invalid number
-/// 9.2233720368547758070x10^5 -> The mantissa contains a value 10 times higher than i64::MAX,
-/// conversion will fail
+///   9.2233720368547758070x10^5 -> The mantissa contains a value 10 times higher than i64::MAX,
+///   conversion will fail
+///
+/// ## Comparison:
+/// Equality, ordering and hashing are based on the numeric value, `10x10^0` and `1x10^1` are equal,
+/// and the display settings are ignored
+///
+/// ## Conversions:
+/// Integers and Decimals convert into a normalized SciNote without losing digits, 128 bit integers
+/// with more significant digits than a Decimal can hold fail with a `PrecisionError`. Converting
+/// back fails with a `PrecisionError` if there's a fractional part, or an `OutOfRangeError` if the
+/// value doesn't fit
+///
+/// ## Display:
+/// The coefficient is displayed with two decimals unless `display_decimals` or `significant_figures`
+/// is set, rounding ties towards zero unless another `rounding` mode is set. Significant figures keep
+/// trailing zeros, `5.00x10^3` is displayed with its three figures. The format precision overrides
+/// both, and width, fill, alignment and the `+` flag apply to the whole notation: `{:>+14.3}`
+///
+/// ## E-notation:
+/// `{:e}` and `{:E}` write E-notation, `6.022e23`, with the whole coefficient unless it's limited
+/// by the precision, the display decimals or the significant figures
+///
+/// ## Engineering notation:
+/// `notation(Notation::Engineering)` displays exponents that are multiples of three, `47x10^3`, and
+/// `notation(Notation::SiPrefix)` replaces them with SI prefixes, `47 k`
+///
+/// ## Rendering:
+/// `to_unicode`, `to_latex`, `to_siunitx` and `to_mathml` render the value for reports, with the
+/// same rounding as `Display`
+///
+/// ## Operators:
+/// `+`, `-`, `*`, `/` and their assigning versions are implemented for every combination of owned
+/// and borrowed values, and `-` for negation. Like integer overflow, an operator that can't produce
+/// a result panics: an exponent out of the i16 range, or a division by zero. Use the `add`, `sub`,
+/// `mul` and `div` methods to get a `SciResult` instead, or the `checked_*`, `saturating_*` and
+/// `overflowing_*` families
 pub struct SciNote {
     pub(crate) coefficient: Decimal,
     pub(crate) exponent: i16,
-    pub(crate) display_decimals: Option<usize>
+    pub(crate) display_decimals: Option<usize>,
+    pub(crate) format: FormatOptions
 }
 
 impl SciNote {
+    /// Maximum amount of significant digits a division result can keep. The quotient is computed
+    /// exactly on the integer mantissas, and rounded once to the requested digits
+    pub const MAX_DIVISION_DIGITS: u32 = 27;
+
+    /// Rounding applied by [SciNote::div]
+    pub(super) const DIVISION_ROUNDING: RoundingMode = RoundingMode::HalfEven;
+
+    /// Largest representable value: 9.999999999999999999999999999x10^32767
+    pub const MAX: SciNote = SciNote {
+        coefficient: Decimal::from_parts(268435455, 1042612833, 542101086, false, 27),
+        exponent: i16::MAX,
+        display_decimals: None,
+        format: FormatOptions { significant_figures: None, rounding: None, notation: None }
+    };
+
+    /// Smallest representable value: -9.999999999999999999999999999x10^32767
+    pub const MIN: SciNote = SciNote {
+        coefficient: Decimal::from_parts(268435455, 1042612833, 542101086, true, 27),
+        exponent: i16::MAX,
+        display_decimals: None,
+        format: FormatOptions { significant_figures: None, rounding: None, notation: None }
+    };
+
     pub fn zero() -> Self {
         Self {
             coefficient: Decimal::ZERO,
             exponent: 0,
-            display_decimals: Some(0)
+            display_decimals: None,
+            format: FormatOptions::default()
         }
     }
-    pub fn build() -> Self {
-        SciNote::default()
+    /// Start building a value from a coefficient and its exponent, see [SciNoteBuilder]
+    pub fn build() -> SciNoteBuilder {
+        SciNoteBuilder::default()
     }
 
-    pub fn coefficient(mut self, coefficient: Decimal) -> Self {
-        self.coefficient = coefficient;
-        self
+    pub fn is_zero(&self) -> bool {
+        self.coefficient.is_zero()
     }
 
-    pub fn exponent(mut self, exponent: i16) -> Self {
-        self.exponent = exponent;
-        self
+    /// Parse a number in scientific notation. The coefficient is normalized, so `315.2x10^14` is
+    /// parsed as `3.152x10^16`, and numbers in engineering notation, like `47x10^3`, are accepted.
+    /// Whitespace around the number and around multiplication signs is ignored. Accepted syntaxes:
+    /// - `6.022x10^23`, the notation used by `Display`
+    /// - `6.022*10^23`, `6.022×10^23` and `6.022 · 10^23`, other multiplication signs
+    /// - `6.022×10²³` and `6.022·10⁻²³`, superscript exponents after any multiplication sign
+    /// - `6.022e23`, `6.022E+23` and `6.022e-23`, E-notation
+    /// - `1.0D+05` and `1.0d-05`, Fortran double precision notation
+    /// - `0`, a plain zero without exponent
+    ///
+    /// Malformed input returns a `SciError::ParseError`, and so does an exponent that doesn't fit
+    /// after normalizing, pointing to the exponent of the input
+    pub fn parse_from_str(input: &str) -> SciResult<Self> {
+        parse::parse_notation_with(input, parse::parse_coefficient, Decimal::is_zero, |coefficient, exponent| {
+            let (coefficient, exponent) = Self::scale_unbounded(coefficient, parse::exponent_in_range(exponent)?)?;
+            Self::from_unbounded(coefficient, exponent, None, FormatOptions::default())
+        })
     }
 
-    pub fn display_decimals(mut self, decimals: usize) -> Self {
-        self.display_decimals = Some(decimals);
-        self
+    /// Count the significant figures of a number in any syntax accepted by [SciNote::parse_from_str].
+    /// Only the coefficient is considered:
+    /// - Leading zeros are never significant, `0.0045x10^3` has two figures
+    /// - Trailing zeros are significant after a decimal point, `5.00x10^3` has three figures
+    /// - Trailing zeros of an integer without decimal point are ambiguous and not counted,
+    ///   `500x10^3` has one figure and `500.x10^3` has three
+    /// - A zero has as many figures as zeros after the decimal point, and at least one
+    ///
+    /// Returns the same errors as [SciNote::parse_from_str] for malformed input
+    pub fn count_significant_figures(input: &str) -> SciResult<usize> {
+        parse::count_significant_figures(input)
     }
 
-    pub fn parse_from_str(input: &str) -> SciResult<Self> {
+    /// Scale the coefficient to one integer digit, adapting the exponent. Fails if the adapted
+    /// exponent is out of the i16 range
+    pub fn normalize(&self) -> SciResult<Self> {
+        let (coefficient, exponent) = Self::scale_unbounded(self.coefficient, i32::from(self.exponent))?;
+        Self::from_unbounded(coefficient, exponent, self.display_decimals, self.format)
+    }
 
-        //  TODO check parsing from zero, if user inputs "0", then coefficient = 0, and exponent = 0
+    pub fn add(&self, input2: &Self) -> SciResult<Self> {
+        let (coefficient, exponent) = self.add_unbounded(input2)?;
+        Self::from_unbounded(
+            coefficient,
+            exponent,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
+    }
 
-        let input = input.to_string();
+    /// Add like [SciNote::add], also returning whether the result was rounded: true when the
+    /// operands are too far apart for the sum to keep every digit, like `1x10^30000 + 1x10^-30000`
+    pub fn add_with_inexact(&self, input2: &Self) -> SciResult<(Self, bool)> {
+        let (coefficient, exponent, inexact) = self.add_unbounded_inexact(input2)?;
+        let result = Self::from_unbounded(
+            coefficient,
+            exponent,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )?;
 
-        //  If notation x10^ is not found, return error, it's not valid scientific notation
-        if !input.contains("x10^") {
-            return Err(SciError::FormatError(Some("Input format was incorrect. Expected CCcx10^EEe".to_string())))
-        }
+        Ok((result, inexact))
+    }
 
-        let full_notation = input.split("x10^").collect::<Vec<&str>>();
-        if full_notation.len() > 2 {
-            return Err(SciError::FormatError(Some("Unexpected format!".to_string())))
-        }
+    /// Subtract like [SciNote::sub], also returning whether the result was rounded
+    pub fn sub_with_inexact(&self, input2: &Self) -> SciResult<(Self, bool)> {
+        self.add_with_inexact(&-input2)
+    }
 
-        let coefficient = full_notation[0]
-            .parse::<Decimal>()?;
+    pub fn mul(&self, input2: &Self) -> SciResult<Self> {
+        let (coefficient, exponent) = self.mul_unbounded(input2)?;
+        Self::from_unbounded(
+            coefficient,
+            exponent,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
+    }
 
-        let exponent = full_notation[1]
-            .parse::<i16>()
-            .map_err(|error| SciError::ParseError(Some(error.to_string())))?;
+    /// Divide by `input2`, keeping up to [SciNote::MAX_DIVISION_DIGITS] significant digits and
+    /// rounding half to even. See [SciNote::div_with_precision] to select the precision and rounding
+    pub fn div(&self, input2: &Self) -> SciResult<Self> {
+        self.div_with_precision(input2, Self::MAX_DIVISION_DIGITS, Self::DIVISION_ROUNDING)
+    }
 
-        Ok(Self {
+    /// Divide by `input2`, rounding the result coefficient to `max_significant_digits` with the
+    /// `rounding` strategy.
+    ///
+    /// Quotients like 1/3 can't be represented exactly, so the result is always limited to a
+    /// precision. `max_significant_digits` must be between 1 and [SciNote::MAX_DIVISION_DIGITS],
+    /// otherwise a `SciError::PrecisionError` is returned. Dividing by zero returns a
+    /// `SciError::OperationError`
+    pub fn div_with_precision(
+        &self,
+        input2: &Self,
+        max_significant_digits: u32,
+        rounding: RoundingMode
+    ) -> SciResult<Self> {
+        let (coefficient, exponent) = self.div_unbounded(input2, max_significant_digits, rounding)?;
+        Self::from_unbounded(
             coefficient,
             exponent,
-            display_decimals: None
-        })
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
     }
 
-    pub fn add(&self, input2: &Self) -> SciResult<Self> {
-        let mut input1 = *self;
-        //  First, equalize exponents
-        let exponent_diff = input1.exponent - input2.exponent;
-        input1.exponent -= exponent_diff;
-
-        //  Second, adapt coefficient to that exponent equalization
-        for _ in 0..exponent_diff.abs() {
-            //  If exponent difference is negative, then we need to make the number smaller by
-            // multiplicating by 0.1
-            let exponent_value = if exponent_diff.is_positive() {
-                Decimal::from(10)
-            } else {
-                Decimal::new(1, 1)
-            };
-            input1.coefficient *= exponent_value;
+    /// Sum both numbers, returning the scaled coefficient and an exponent that may be out of the i16 range
+    pub(super) fn add_unbounded(&self, input2: &Self) -> SciResult<(Decimal, i32)> {
+        self.add_unbounded_inexact(input2).map(|(coefficient, exponent, _)| (coefficient, exponent))
+    }
+
+    /// Sum both numbers like [SciNote::add_unbounded], also returning whether the sum was rounded
+    pub(super) fn add_unbounded_inexact(&self, input2: &Self) -> SciResult<(Decimal, i32, bool)> {
+        //  Adding zero doesn't change the other operand
+        if self.is_zero() {
+            return Ok((input2.coefficient, i32::from(input2.exponent), false))
+        }
+        if input2.is_zero() {
+            return Ok((self.coefficient, i32::from(self.exponent), false))
         }
 
-        //  Third, with exponents equalized, sum coefficients
-        let mut coefficient_sum_result = input1.coefficient + input2.coefficient;
+        //  First, equalize exponents. The operand with the bigger exponent is kept as it is and the
+        // other one is shrunk to match it, so the alignment can never overflow the coefficient
+        let (bigger, smaller) = if self.exponent >= input2.exponent {
+            (self, input2)
+        } else {
+            (input2, self)
+        };
+        let exponent_diff = (i32::from(bigger.exponent) - i32::from(smaller.exponent)) as u32;
 
-        //  Scale to always be 1 digit integer. If there's more than one digit, shift right, if there's less, shift left
-        //  TODO implement scale function here
+        //  Second, move the point of the smaller coefficient in a single step. Its digits below the
+        // precision of a Decimal are rounded away, and it's absorbed if all of them are
+        let (smaller_coefficient, shift_inexact) = Self::shift_point_left(smaller.coefficient, exponent_diff);
 
+        //  Third, with exponents equalized, sum coefficients. Decimal rounds the sum if it doesn't fit
+        let coefficient_sum_result = bigger.coefficient
+            .checked_add(smaller_coefficient)
+            .ok_or(SciError::OperationError(Some("Coefficient addition overflowed".to_string())))?;
+        let sum_inexact = !Self::is_exact_sum(bigger.coefficient, smaller_coefficient, coefficient_sum_result);
 
-        //  Obtaining the mantissa length to convert into a single int, with the needed decimal places
-        let old_mantissa = coefficient_sum_result.mantissa();
-        let old_scale = coefficient_sum_result.scale();
+        let (coefficient, exponent) = Self::scale_unbounded(coefficient_sum_result, i32::from(bigger.exponent))?;
+        Ok((coefficient, exponent, shift_inexact || sum_inexact))
+    }
 
-        //  Rescale the coefficient
-        let scale_shift_amount = if old_scale as usize > old_mantissa.to_string().len() {
-            //  In this case the shift must be negative, exponent decreases
-            // let shift = (old_scale - old_mantissa.to_string().len() as u32) as i16;
-            let shift = -(old_scale as i16);
-            // coefficient_sum_result.set_scale(shift as u32)
-            coefficient_sum_result.set_scale(old_mantissa.to_string().len() as u32 - 1)?;
-            shift
-        } else {
-            //  Shift is positive, exponent increases
-            let shift = (old_mantissa.to_string().len() as u32 - old_scale - 1) as i16;
-            coefficient_sum_result.set_scale(old_mantissa.to_string().len() as u32 - 1)?;
-            shift
-        };
+    /// Divide the coefficient by 10^places moving its point, rounding half to even the digits past
+    /// the precision of a Decimal. Returns the shifted coefficient and whether it was rounded
+    fn shift_point_left(coefficient: Decimal, places: u32) -> (Decimal, bool) {
+        let scale = coefficient.scale() + places;
+        if scale <= MAX_DECIMAL_PRECISION as u32 {
+            return (Decimal::from_i128_with_scale(coefficient.mantissa(), scale), false)
+        }
+
+        //  The mantissa has at most 29 digits, dropping 30 or more always rounds it to zero
+        let dropped_digits = scale - MAX_DECIMAL_PRECISION as u32;
+        if dropped_digits >= 30 {
+            return (Decimal::ZERO, true)
+        }
+
+        let divisor = 10u128.pow(dropped_digits);
+        let magnitude = coefficient.mantissa().unsigned_abs();
+        let (mut quotient, remainder) = (magnitude / divisor, magnitude % divisor);
+        if remainder * 2 > divisor || (remainder * 2 == divisor && quotient % 2 == 1) {
+            quotient += 1;
+        }
+
+        let mut shifted = Decimal::from_i128_with_scale(quotient as i128, MAX_DECIMAL_PRECISION as u32);
+        shifted.set_sign_negative(coefficient.is_sign_negative());
+        (shifted, remainder != 0)
+    }
+
+    /// Whether `sum` is exactly `first + second`, computing the exact sum on the widest integers.
+    /// Both operands have at most 28 decimals and one integer digit, so the sum fits
+    fn is_exact_sum(first: Decimal, second: Decimal, sum: Decimal) -> bool {
+        let scale = first.scale().max(second.scale());
+        let at_scale = |value: Decimal| value.mantissa() * 10i128.pow(scale - value.scale());
+
+        at_scale(first) + at_scale(second) == at_scale(sum)
+    }
+
+    /// Multiply both numbers, returning the scaled coefficient and an exponent that may be out of the i16 range
+    pub(super) fn mul_unbounded(&self, input2: &Self) -> SciResult<(Decimal, i32)> {
+        //  A zero operand makes the product zero, whatever the other exponent is
+        if self.is_zero() || input2.is_zero() {
+            return Ok((Decimal::ZERO, 0))
+        }
+
+        //  Multiply coefficients, the product of two one digit integers has at most two integer digits
+        let coefficient_product = self.coefficient
+            .checked_mul(input2.coefficient)
+            .ok_or(SciError::OperationError(Some("Coefficient multiplication overflowed".to_string())))?;
+
+        //  Add exponents. It's done in a wider type because the scaling may bring it back into range
+        let exponent_sum = i32::from(self.exponent) + i32::from(input2.exponent);
+
+        Self::scale_unbounded(coefficient_product, exponent_sum)
+    }
+
+    /// Divide both numbers, returning the scaled coefficient and an exponent that may be out of the i16 range
+    pub(super) fn div_unbounded(
+        &self,
+        input2: &Self,
+        max_significant_digits: u32,
+        rounding: RoundingMode
+    ) -> SciResult<(Decimal, i32)> {
+        if max_significant_digits == 0 || max_significant_digits > Self::MAX_DIVISION_DIGITS {
+            return Err(SciError::PrecisionError(Some(format!(
+                "Significant digits must be between 1 and {}", Self::MAX_DIVISION_DIGITS
+            ))))
+        }
+
+        if input2.is_zero() {
+            return Err(SciError::OperationError(Some("Division by zero".to_string())))
+        }
+
+        //  Zero divided by any other number is zero
+        if self.is_zero() {
+            return Ok((Decimal::ZERO, 0))
+        }
+
+        //  Divide the mantissas exactly, and round the quotient once, with the remainder deciding the
+        // rounding of the last digit. Rounding Decimal's quotient again would round twice, as it's
+        // already rounded to 28 digits. Trailing zeros of exact quotients are dropped, they come
+        // from the division precision and not from the operands
+        let (quotient, power, remainder_found) =
+            BigSciNote::from(*self).div_unbounded(&BigSciNote::from(*input2), max_significant_digits)?;
+        let (quotient, power, _) =
+            big::round_significant(&quotient, power, max_significant_digits, rounding, remainder_found);
+        let (quotient, power) = big::strip_trailing_zeros(quotient, power);
+
+        //  The quotient has at most 27 digits, it always fits a Decimal
+        let digits = big::digit_count(quotient.magnitude());
+        let mantissa = i128::try_from(&quotient)
+            .map_err(|error| SciError::Unexpected(format!("Unexpected quotient overflow: {}", error)))?;
+        let coefficient = Decimal::try_from_i128_with_scale(mantissa, digits - 1)?;
+
+        //  The exponent of the normalized quotient, the exponents of both operands fit an i32
+        let exponent = power + i64::from(digits) - 1;
+        Ok((coefficient, exponent as i32))
+    }
+
+    /// Scale a coefficient that may have more or less than one integer digit to one integer digit,
+    /// adapting the exponent accordingly. The exponent is not checked against the i16 range
+    pub(super) fn scale_unbounded(coefficient: Decimal, exponent: i32) -> SciResult<(Decimal, i32)> {
+        //  Scale to always be 1 digit integer. If there's more than one digit, shift right, if there's less, shift left
+        let (coefficient, scale_shift_amount) = Self::scale_to_one_integer_digit(coefficient)?;
 
         //  Adapt the exponent to the new scale
-        let new_exponent = input1.exponent + scale_shift_amount;
+        let exponent = exponent
+            .checked_add(i32::from(scale_shift_amount))
+            .ok_or(SciError::ExponentError(Some("Exponent out of range after scaling".to_string())))?;
 
-        let display_decimals = if let Some(decimals) = input1.display_decimals {
-            Some(decimals)
-        } else if let Some(decimals) = input2.display_decimals {
-            Some(decimals)
-        } else {
-            None
-        };
+        Ok((coefficient, exponent))
+    }
+
+    /// Build a new instance from a scaled coefficient and an exponent, failing if the exponent is
+    /// out of the i16 range
+    pub(super) fn from_unbounded(
+        coefficient: Decimal,
+        exponent: i32,
+        display_decimals: Option<usize>,
+        format: FormatOptions
+    ) -> SciResult<Self> {
+        //  There's a single zero, 0x10^0, regardless of the exponent or sign it was computed with
+        if coefficient.is_zero() {
+            return Ok(Self {
+                coefficient: Decimal::ZERO,
+                exponent: 0,
+                display_decimals,
+                format
+            })
+        }
+
+        let exponent = i16::try_from(exponent)
+            .map_err(|_| SciError::ExponentError(Some(format!("Exponent {} out of range", exponent))))?;
 
         Ok(Self {
-            coefficient: coefficient_sum_result,
-            exponent: new_exponent,
-            display_decimals
+            coefficient,
+            exponent,
+            display_decimals,
+            format
         })
     }
 
@@ -146,90 +408,23 @@ impl SciNote {
     /// A positive shift number means the exponent will grow, and a negative, it'll shrink
     pub(super) fn scale_to_one_integer_digit(input_coefficient: Decimal) -> SciResult<(Decimal, i16)> {
 
-        //  Initialize parser variables
-        let input_string = input_coefficient.to_string();
-
-        let integer_string;
-        let decimal_string;
-        if !input_string.contains('.') {
-            //  If there's no decimal point, then the number is an integer
-            integer_string = input_string.as_str();
-            decimal_string = "";
-        } else {
-            //  Otherwise, it's decimal
-            let parsed = input_string.split('.').collect::<Vec<&str>>();
-            integer_string = parsed[0];
-            decimal_string = parsed[1];
+        //  Zero cannot be scaled, there's no digit to move to the integer part
+        if input_coefficient.is_zero() {
+            return Ok((Decimal::ZERO, 0))
         }
 
-        //  Parse into Decimal to validate their lengths, and determine if they contain leading zeroes
-        let integer_parsed = integer_string.parse::<Decimal>()?;
-        let decimal_parsed = decimal_string.parse::<Decimal>().unwrap_or(Decimal::ZERO);
-
-        //  Evaluate if we need to shift right
-        match (integer_string.len().cmp(&integer_parsed.to_string().len()), integer_string.len().cmp(&1usize)) {
-            (Ordering::Equal, Ordering::Greater) => {
-                //  Example case: 2134.xxxx
-                //  No leading zeros and need to adjust right, exponent will increase and coefficient will decrease
-                let right_shift = integer_string.len() as i16 - 1;
-                let new_scale = right_shift as u32 - decimal_string.len() as u32;
-                
-                let mut output_coefficient = input_coefficient;
-                output_coefficient.set_scale(new_scale)?;
-
-                return Ok((output_coefficient, right_shift))
-            },
-            (Ordering::Equal, Ordering::Equal) => {
-                //  Example case: 2.xxxx or 0.xxxx
-                //  Don't need to shift in the first case, return as it is with shift = 0
-                if integer_parsed != Decimal::ZERO {
-                    dbg!("Returned here");
-                    return Ok((input_coefficient, 0))
-                }
-                //  Otherwise, continue and evaluate if we need to shift left
-            },
-            (Ordering::Less, _) => {
-                //  String integer portion cannot be smaller than the Decimal parsed integer portion,
-                // it'd be the inverse case of leading zeros
-                return Err(SciError::Unexpected("Unexpected parse error".to_string()))
-            },
-            (_, Ordering::Less) => {
-                //  Integer portion in string format cannot have a length smaller than 1, it'd be
-                // non-existent. Ex: .xxxx, the zero is missing
-                return Err(SciError::Unexpected("Invalid parsed integer number".to_string()))
-            }
-            (Ordering::Greater, _) => {
-                //  Continue and evaluate shift left
-            }
-        }
+        //  The coefficient is mantissa x 10^-scale. Keeping the mantissa and setting the scale to its
+        // amount of digits minus one leaves a single integer digit, without losing any digit
+        let digits = POWERS_OF_TEN.partition_point(|power| *power <= input_coefficient.mantissa().unsigned_abs());
+        let new_scale = digits as u32 - 1;
 
-        //  If decimal portion is zero, then the number is an integer. If we got to this point
-        // without any shifts, there's something wrong, it's best to just return and have a valid number
-        if decimal_parsed.is_zero() {
-            dbg!("Returned here");
-            return Ok((input_coefficient, 0))
-        }
+        let mut output_coefficient = input_coefficient;
+        output_coefficient.set_scale(new_scale)?;
 
-        //  Evaluate if we need to shift left
-        match decimal_string.len().cmp(&decimal_parsed.to_string().len()) {
-            Ordering::Equal | Ordering::Greater => {
-                //  We need to shift left, determine the amount of places to shift
-                //  Example case: x.000123 -> Shift left by 4 places
-                //  Other example: x.1 -> Shift left by 1 space
-                let left_shift = decimal_string.len() - decimal_parsed.to_string().len() + 1;
-                let new_scale = decimal_parsed.to_string().len() as u32 - 1;
-                
-                let mut output_coefficient = input_coefficient;
-                output_coefficient.set_scale(new_scale)?;
-
-                Ok((output_coefficient, -(left_shift as i16)))
-            },
-            Ordering::Less => {
-                //  In this case, the decimal portion in string format was shorter than the decimal
-                // parsed one. Error that should not happen
-                Err(SciError::Unexpected("Unexpected parse error".to_string()))
-            }
-        }
+        //  The places the point moved, positive if it moved left
+        let shift = new_scale as i16 - input_coefficient.scale() as i16;
+
+        Ok((output_coefficient, shift))
     }
 }
 
@@ -238,50 +433,8 @@ impl Default for SciNote {
         Self {
             coefficient: Decimal::from(1),
             exponent: i16::default(),
-            display_decimals: None
+            display_decimals: None,
+            format: FormatOptions::default()
         }
     }
 }
-
-impl Display for SciNote {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}x10^{}",
-            self.coefficient.round_dp_with_strategy(
-                self.display_decimals.unwrap_or(2) as u32, RoundingStrategy::MidpointTowardZero
-            ),
-            self.exponent
-        )
-    }
-}
-
-/*
-impl std::ops::Add for ScientificNotation {
-    type Output = ScientificNotation;
-    fn add(self, rhs: Self) -> Self::Output {
-        todo!()
-    }
-}
-
-impl std::ops::Sub for ScientificNotation {
-    type Output = ScientificNotation;
-    fn sub(self, rhs: Self) -> Self::Output {
-        todo!()
-    }
-}
-
-impl std::ops::Mul for ScientificNotation{
-    type Output = ScientificNotation;
-    fn mul(self, rhs: Self) -> Self::Output {
-        todo!()
-    }
-}
-
-impl std::ops::Div for ScientificNotation {
-    type Output = ScientificNotation;
-    fn div(self, rhs: Self) -> Self::Output {
-        todo!()
-    }
-}
-*/
\ No newline at end of file
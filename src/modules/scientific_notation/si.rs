@@ -0,0 +1,129 @@
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::big::{self, BigSciNote};
+use crate::modules::scientific_notation::error::{ParsePart, SciError, SciParseError, SciResult};
+use crate::modules::scientific_notation::format::FormatOptions;
+use crate::modules::scientific_notation::parse::{self, Fragment};
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+/// SI prefixes for the exponents that are multiples of three, from quetta to quecto, including
+/// the ones added in 2022: ronna, quetta, ronto and quecto
+const ENGINEERING_PREFIXES: [(i32, &str); 21] = [
+    (30, "Q"),
+    (27, "R"),
+    (24, "Y"),
+    (21, "Z"),
+    (18, "E"),
+    (15, "P"),
+    (12, "T"),
+    (9, "G"),
+    (6, "M"),
+    (3, "k"),
+    (0, ""),
+    (-3, "m"),
+    (-6, "µ"),
+    (-9, "n"),
+    (-12, "p"),
+    (-15, "f"),
+    (-18, "a"),
+    (-21, "z"),
+    (-24, "y"),
+    (-27, "r"),
+    (-30, "q")
+];
+
+/// Other prefixes accepted when parsing: the ones that aren't multiples of three, the Greek mu,
+/// and `u`, the ASCII replacement of micro
+const OTHER_PREFIXES: [(i32, &str); 6] = [
+    (2, "h"),
+    (1, "da"),
+    (-1, "d"),
+    (-2, "c"),
+    (-6, "μ"),
+    (-6, "u")
+];
+
+/// Prefix of an exponent that's a multiple of three, `None` if it's out of the prefixes range
+pub(super) fn prefix_of(exponent: i32) -> Option<&'static str> {
+    ENGINEERING_PREFIXES
+        .iter()
+        .find(|(prefix_exponent, _)| *prefix_exponent == exponent)
+        .map(|(_, prefix)| *prefix)
+}
+
+impl SciNote {
+    /// Engineering coefficient and SI prefix of the value, `4.7x10^4` is `(47, "k")`. Values
+    /// without prefix, between 1 and 1000, have an empty one. Returns `None` if the value is out of
+    /// the prefixes range, from quecto, 10^-30, to quetta, 10^30
+    pub fn to_si_prefix(&self) -> Option<(Decimal, &'static str)> {
+        let (coefficient, exponent) = self.to_engineering();
+        prefix_of(exponent).map(|prefix| (coefficient, prefix))
+    }
+
+    /// Parse a number followed by an optional SI prefix: `4.7k`, `220n`, `3.3 µ` or `1.5 G`. The
+    /// prefix may be separated by whitespace, and micro may be written as `µ`, `μ` or `u`. Besides
+    /// the multiples of three, hecto, deca, deci and centi are accepted.
+    ///
+    /// Malformed input returns a `SciError::ParseError`, pointing to the coefficient or the prefix
+    pub fn parse_si(input: &str) -> SciResult<Self> {
+        parse_prefixed(input, parse::parse_coefficient, |coefficient, exponent| {
+            let (coefficient, exponent) = Self::scale_unbounded(coefficient, exponent)?;
+            Self::from_unbounded(coefficient, exponent, None, FormatOptions::default())
+        })
+    }
+}
+
+impl BigSciNote {
+    /// Parse a number followed by an optional SI prefix like [SciNote::parse_si], keeping every
+    /// digit of the coefficient
+    pub fn parse_si(input: &str) -> SciResult<Self> {
+        parse_prefixed(input, big::parse_coefficient, |(mantissa, decimals), exponent| {
+            BigSciNote::new(mantissa, i64::from(exponent) - i64::from(decimals))
+        })
+    }
+}
+
+/// Split the input into its coefficient, parsed with `parse_coefficient`, and the exponent of its
+/// SI prefix, zero without prefix, and make the value with `build`. Only a coefficient with tens
+/// of thousands of digits takes the value out of the exponent range, so it's reported there
+fn parse_prefixed<C, T>(
+    input: &str,
+    parse_coefficient: impl Fn(Fragment) -> SciResult<C>,
+    build: impl FnOnce(C, i32) -> SciResult<T>
+) -> SciResult<T> {
+    let start = input.len() - input.trim_start().len();
+    let trimmed = input.trim();
+
+    //  The coefficient is made of digits, signs and a point, the prefix starts after them
+    let split = trimmed
+        .find(|character: char| !(character.is_ascii_digit() || matches!(character, '+' | '-' | '.')))
+        .unwrap_or(trimmed.len());
+    let coefficient_fragment = Fragment {
+        text: &trimmed[..split],
+        position: start
+    };
+    let coefficient = parse_coefficient(coefficient_fragment)?;
+
+    let prefix = trimmed[split..].trim_start();
+    let prefix_position = start + trimmed.len() - prefix.len();
+    let exponent = ENGINEERING_PREFIXES
+        .iter()
+        .chain(OTHER_PREFIXES.iter())
+        .find(|(_, symbol)| *symbol == prefix)
+        .map(|(exponent, _)| *exponent)
+        .ok_or_else(|| SciParseError::new(
+            ParsePart::Prefix,
+            prefix_position,
+            prefix,
+            format!("unknown SI prefix '{}'", prefix)
+        ))?;
+
+    build(coefficient, exponent).map_err(|error| match error {
+        SciError::ExponentError(_) => SciParseError::new(
+            ParsePart::Coefficient,
+            coefficient_fragment.position,
+            coefficient_fragment.text,
+            "exponent out of range"
+        ).into(),
+        error => error
+    })
+}
@@ -0,0 +1,292 @@
+use std::collections::HashSet;
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+use crate::{BigSciNote, Notation, ParsePart, RoundingMode, SciError, SciNote};
+use crate::modules::scientific_notation::big;
+
+const PI: &str = "3.14159265358979323846264338327950288419716939937510x10^0";
+
+fn parse(input: &str) -> BigSciNote {
+    BigSciNote::parse_from_str(input).unwrap()
+}
+
+#[test]
+fn test_parse_keeps_every_digit() {
+    let value = parse(PI);
+    assert_eq!(value.mantissa, "314159265358979323846264338327950288419716939937510".parse::<BigInt>().unwrap());
+    assert_eq!(value.exponent, 0);
+
+    //  Mantissas beyond i64 and Decimal are accepted, and normalized
+    let value = parse("-123456789012345678901234567890123456789.5e10");
+    assert_eq!(value.mantissa, "-1234567890123456789012345678901234567895".parse::<BigInt>().unwrap());
+    assert_eq!(value.exponent, 48);
+
+    //  Every syntax of SciNote is accepted
+    for input in ["6.022x10^23", "6.022*10^23", "6.022 × 10²³", "6.022E+23", "0.6022D24", "602.2e21"] {
+        assert_eq!(parse(input), parse("6.022x10^23"), "input: {}", input);
+    }
+    assert!(parse("0").is_zero());
+    assert!(parse("-0.000x10^12").is_zero());
+}
+
+#[test]
+fn test_parse_errors() {
+    let Err(SciError::ParseError(error)) = BigSciNote::parse_from_str("3.1.4x10^2") else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(error.part(), ParsePart::Coefficient);
+    assert_eq!(error.position(), 3);
+
+    let Err(SciError::ParseError(error)) = BigSciNote::parse_from_str("3.14x10^a") else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(error.part(), ParsePart::Exponent);
+
+    assert!(matches!(BigSciNote::parse_from_str("5"), Err(SciError::ParseError(_))));
+    let Err(SciError::ParseError(error)) = BigSciNote::parse_from_str("99.9x10^32767") else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(error.part(), ParsePart::Exponent);
+    assert_eq!(error.position(), 8);
+    assert!("12e3".parse::<BigSciNote>().is_ok());
+    assert!(BigSciNote::try_from("12e3").is_ok());
+}
+
+#[test]
+fn test_arithmetic_without_losing_digits() {
+    let sum = parse("1x10^40") + parse("1x10^-40");
+    assert_eq!(sum.mantissa, BigInt::from(10).pow(80) + 1);
+    assert_eq!(sum.exponent, 40);
+
+    let difference = parse("1x10^40") - parse("1x10^-40");
+    assert_eq!(difference.mantissa, BigInt::from(10).pow(80) - 1);
+    assert_eq!(difference.exponent, 39);
+
+    let product = parse("1.23456789012345678901234567890x10^5") * parse("-9.87654321098765432109876543210x10^-3");
+    assert_eq!(product, parse("-1.2193263113702179522618503273362292333223746380111126352690x10^3"));
+
+    assert!((parse("4.2x10^7") - parse("4.2x10^7")).is_zero());
+}
+
+#[test]
+fn test_long_accumulation() {
+    //  Decimal would lose the small terms once the sum has 28 digits
+    let mut sum = BigSciNote::zero();
+    for _ in 0..1000 {
+        sum += parse("1x10^20");
+        sum += parse("1x10^-20");
+    }
+    assert_eq!(sum, parse(&format!("1.{}1x10^23", "0".repeat(39))));
+}
+
+#[test]
+fn test_division() {
+    let third = parse("1x10^0") / parse("3x10^0");
+    assert_eq!(third.mantissa, "3".repeat(50).parse::<BigInt>().unwrap());
+    assert_eq!(third.exponent, -1);
+
+    let two_thirds = parse("2x10^0").div_with_precision(&parse("3x10^0"), 60, RoundingMode::HalfEven).unwrap();
+    assert_eq!(two_thirds.mantissa, format!("{}7", "6".repeat(59)).parse::<BigInt>().unwrap());
+
+    let two_thirds = parse("-2x10^0").div_with_precision(&parse("3x10^0"), 5, RoundingMode::TowardZero).unwrap();
+    assert_eq!(two_thirds, parse("-6.6666x10^-1"));
+
+    //  Exact quotients don't keep the trailing zeros of the precision
+    let quotient = parse("1x10^3") / parse("8x10^0");
+    assert_eq!(quotient.mantissa, BigInt::from(125));
+    assert_eq!(quotient.exponent, 2);
+
+    assert!(matches!(parse("1x10^0").div(&BigSciNote::zero()), Err(SciError::OperationError(_))));
+    assert!(matches!(
+        parse("1x10^0").div_with_precision(&parse("3x10^0"), 0, RoundingMode::HalfEven),
+        Err(SciError::PrecisionError(_))
+    ));
+}
+
+#[test]
+fn test_rounding() {
+    let value = parse(PI);
+    assert_eq!(value.round_sig_figs(6, RoundingMode::HalfEven).unwrap(), parse("3.14159x10^0"));
+    assert_eq!(value.round_sig_figs(6, RoundingMode::Ceiling).unwrap(), parse("3.14160x10^0"));
+    assert_eq!(value.round_decimals(1, RoundingMode::HalfEven).unwrap(), parse("3.1x10^0"));
+
+    let rounded = parse("9.99x10^2").round_sig_figs(2, RoundingMode::HalfEven).unwrap();
+    assert_eq!(rounded.mantissa, BigInt::from(10));
+    assert_eq!(rounded.exponent, 3);
+
+    assert!(matches!(parse("9.99x10^32767").round_sig_figs(2, RoundingMode::HalfEven), Err(SciError::ExponentError(_))));
+    assert!(matches!(value.round_sig_figs(0, RoundingMode::HalfEven), Err(SciError::PrecisionError(_))));
+}
+
+#[test]
+fn test_display() {
+    let value = parse(PI);
+    assert_eq!(value.to_string(), "3.14x10^0");
+    assert_eq!(format!("{:.30}", value), "3.141592653589793238462643383280x10^0");
+    assert_eq!(value.clone().significant_figures(40).to_string(), "3.141592653589793238462643383279502884197x10^0");
+    assert_eq!(value.clone().display_decimals(4).rounding(RoundingMode::Ceiling).to_string(), "3.1416x10^0");
+    assert_eq!(format!("{:>+12}|", value), "  +3.14x10^0|");
+
+    assert_eq!(parse("5x10^3").significant_figures(3).to_string(), "5.00x10^3");
+    assert_eq!(parse("-9.999x10^-3").to_string(), "-1.00x10^-2");
+    assert_eq!(BigSciNote::zero().to_string(), "0x10^0");
+
+    assert_eq!(format!("{:e}", parse("-1.602176634x10^-19")), "-1.602176634e-19");
+    assert_eq!(format!("{:.2E}", parse("6.02214076x10^23")), "6.02E23");
+}
+
+#[test]
+fn test_comparison() {
+    assert_eq!(parse("1.50x10^3"), parse("15x10^2"));
+    assert!(parse("1.5x10^3") < parse("1.50000000000000000000000000000001x10^3"));
+    assert!(parse("-2x10^3") < parse("-1.99999999999999999999999999999999x10^3"));
+    assert!(parse("-1x10^30") < BigSciNote::zero());
+    assert!(parse("1x10^-30") > BigSciNote::zero());
+
+    let set = ["1.50x10^3", "15x10^2", "1.5e3", "2e3"].map(parse).into_iter().collect::<HashSet<BigSciNote>>();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_sci_note_conversions() {
+    let value = SciNote::parse_from_str("-6.02214076x10^23").unwrap();
+    let big = BigSciNote::from(value);
+    assert_eq!(big, parse("-6.02214076x10^23"));
+    assert_eq!(SciNote::try_from(big).unwrap(), value);
+
+    let big = BigSciNote::from(SciNote::MAX);
+    assert_eq!(SciNote::try_from(big).unwrap(), SciNote::MAX);
+
+    let converted = SciNote::try_from(BigSciNote::zero()).unwrap();
+    assert!(converted.is_zero());
+
+    //  Too many digits for a Decimal, they have to be rounded first
+    assert!(matches!(SciNote::try_from(parse(PI)), Err(SciError::PrecisionError(_))));
+    let rounded = parse(PI).round_sig_figs(28, RoundingMode::HalfEven).unwrap();
+    assert_eq!(
+        SciNote::try_from(rounded).unwrap().coefficient,
+        Decimal::from_i128_with_scale(3141592653589793238462643383, 27)
+    );
+}
+
+#[test]
+fn test_conversions_ignore_trailing_zeros() {
+    let value = parse("1.000000000000000000000000000000000x10^0");
+    assert_eq!(SciNote::try_from(value).unwrap(), SciNote::parse_from_str("1x10^0").unwrap());
+
+    let value = parse(&format!("-9.999999999999999999999999999{}x10^32767", "0".repeat(40)));
+    assert_eq!(SciNote::try_from(value).unwrap(), SciNote::MIN);
+
+    let value = BigSciNote::from(SciNote::parse_from_str("1.20x10^5").unwrap()) * parse("1.0000x10^0");
+    assert_eq!(SciNote::try_from(value).unwrap().coefficient, Decimal::new(12, 1));
+}
+
+#[test]
+fn test_constructors() {
+    let value = BigSciNote::new(BigInt::from(314159), -5).unwrap();
+    assert_eq!(value, parse("3.14159x10^0"));
+    assert_eq!(BigSciNote::new(BigInt::from(-25), 32766).unwrap(), parse("-2.5x10^32767"));
+    assert!(matches!(BigSciNote::new(BigInt::from(25), 32767), Err(SciError::ExponentError(_))));
+    assert!(BigSciNote::new(BigInt::default(), 40000).unwrap().is_zero());
+
+    //  128 bit integers keep every digit
+    assert_eq!(BigSciNote::from(u128::MAX), parse("3.40282366920938463463374607431768211455x10^38"));
+    assert_eq!(BigSciNote::from(i128::MIN), parse("-1.70141183460469231731687303715884105728x10^38"));
+    assert_eq!(BigSciNote::from(-1200i16).mantissa, BigInt::from(-12));
+    assert_eq!(BigSciNote::from(Decimal::new(-125, 3)), parse("-1.25x10^-1"));
+
+    assert_eq!(BigSciNote::try_from(String::from("6.022x10^23")).unwrap(), parse("6.022x10^23"));
+    assert_eq!(BigSciNote::zero().display_decimals, None);
+}
+
+#[test]
+fn test_digit_count() {
+    for digits in 1..400u32 {
+        let power = BigInt::from(10).pow(digits);
+        assert_eq!(big::digit_count((&power - 1u32).magnitude()), digits);
+        assert_eq!(big::digit_count(power.magnitude()), digits + 1);
+        assert_eq!(big::digit_count((&power + 1u32).magnitude()), digits + 1);
+    }
+    assert_eq!(big::digit_count(BigInt::default().magnitude()), 1);
+    assert_eq!(big::digit_count(BigInt::from(u64::MAX).magnitude()), 20);
+
+    //  Powers of two are the closest values to the boundaries of the estimate
+    for bits in 60..1000u32 {
+        let power = BigInt::from(1) << bits;
+        assert_eq!(big::digit_count(power.magnitude()), power.to_string().len() as u32);
+    }
+}
+
+#[test]
+fn test_notations() {
+    let value = parse("4.7000000000000000000000000000000001x10^4");
+    assert_eq!(value.clone().notation(Notation::Engineering).to_string(), "47.00x10^3");
+    assert_eq!(value.clone().notation(Notation::Engineering).significant_figures(3).to_string(), "47.0x10^3");
+    assert_eq!(value.clone().notation(Notation::SiPrefix).display_decimals(1).to_string(), "47.0 k");
+    assert_eq!(format!("{:.34}", value.clone().notation(Notation::Engineering)), "47.0000000000000000000000000000000010x10^3");
+    assert_eq!(format!("{:e}", value.clone().notation(Notation::Engineering)), "47.000000000000000000000000000000001e3");
+
+    //  A rounding that carries into a new digit moves to the next multiple of three
+    assert_eq!(parse("9.999996x10^5").notation(Notation::Engineering).to_string(), "1.00x10^6");
+    assert_eq!(parse("1x10^5").notation(Notation::Engineering).to_string(), "100x10^3");
+    assert_eq!(parse("-2.2x10^-8").notation(Notation::SiPrefix).to_string(), "-22 n");
+    assert_eq!(parse("1.5x10^2").notation(Notation::SiPrefix).to_string(), "150");
+    assert_eq!(parse("1.5x10^40").notation(Notation::SiPrefix).to_string(), "1.5x10^40");
+}
+
+#[test]
+fn test_renderers() {
+    let value = parse(PI).significant_figures(31);
+    assert_eq!(value.to_unicode(), "3.141592653589793238462643383280 × 10⁰");
+    assert_eq!(parse("-1.6x10^-19").to_latex(), "-1.6 \\times 10^{-19}");
+    assert_eq!(parse("6.022x10^23").to_siunitx(), "\\num{6.02e23}");
+    assert_eq!(
+        parse("-1.6x10^-19").to_mathml(),
+        "<math><mo>-</mo><mn>1.6</mn><mo>×</mo><msup><mn>10</mn><mrow><mo>-</mo><mn>19</mn></mrow></msup></math>"
+    );
+}
+
+#[test]
+fn test_parse_si() {
+    let value = BigSciNote::parse_si("4.70000000000000000000000000000000001 k").unwrap();
+    assert_eq!(value, parse("4.70000000000000000000000000000000001x10^3"));
+    assert_eq!(BigSciNote::parse_si("-220n").unwrap(), parse("-2.2x10^-7"));
+    assert_eq!(BigSciNote::parse_si("15").unwrap(), parse("1.5x10^1"));
+
+    let Err(SciError::ParseError(error)) = BigSciNote::parse_si("4.7 kk") else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(error.part(), ParsePart::Prefix);
+    assert_eq!(error.position(), 4);
+}
+
+#[test]
+fn test_overflow_aware_arithmetic() {
+    let max = parse("9.9999999999999999999999999999999999x10^32767");
+    let min_positive = parse("1x10^-32768");
+
+    assert_eq!(max.checked_add(&parse("1x10^0")), Some(max.clone() + parse("1x10^0")));
+    assert_eq!(max.checked_add(&max), None);
+    assert_eq!(max.checked_sub(&-&max), None);
+    assert_eq!(max.checked_mul(&parse("1x10^1")), None);
+    assert_eq!(min_positive.checked_div(&parse("1x10^1")), None);
+    assert_eq!(max.checked_div(&BigSciNote::zero()), None);
+
+    let (sum, overflowed) = max.overflowing_add(&max);
+    assert!(overflowed);
+    assert_eq!(sum.exponent, i16::MIN);
+    assert_eq!(sum.mantissa, max.mantissa.clone() * 2);
+
+    let (product, overflowed) = parse("2x10^3").overflowing_mul(&parse("3x10^4"));
+    assert!(!overflowed);
+    assert_eq!(product, parse("6x10^7"));
+
+    let (quotient, overflowed) = min_positive.overflowing_div(&parse("3x10^0"));
+    assert!(overflowed);
+    assert_eq!(quotient.exponent, i16::MAX);
+    assert_eq!(quotient.mantissa, "3".repeat(50).parse::<BigInt>().unwrap());
+
+    let (difference, overflowed) = parse("1x10^5").overflowing_sub(&parse("1x10^5"));
+    assert!(!overflowed);
+    assert!(difference.is_zero());
+}
@@ -0,0 +1,81 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use rust_decimal::Decimal;
+use crate::SciNote;
+
+#[test]
+fn test_equality_across_exponents() {
+    let num1 = SciNote::build().coefficient(Decimal::from(10)).exponent(0).finish().unwrap();
+    let num2 = SciNote::parse_from_str("1x10^1").unwrap();
+    let num3 = SciNote::parse_from_str("0.001x10^4").unwrap();
+
+    assert_eq!(num1, num2);
+    assert_eq!(num2, num3);
+    assert_ne!(num1, SciNote::parse_from_str("1x10^0").unwrap());
+}
+
+#[test]
+fn test_equality_ignores_display_decimals() {
+    let num1 = SciNote::parse_from_str("5.5x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("5.50x10^3").unwrap().display_decimals(5);
+
+    assert_eq!(num1, num2);
+}
+
+#[test]
+fn test_equality_of_zeros() {
+    let zero1 = SciNote::parse_from_str("0x10^0").unwrap();
+    let zero2 = SciNote::parse_from_str("-0.000x10^3").unwrap();
+
+    assert_eq!(zero1, zero2);
+    assert_eq!(zero1, SciNote::zero());
+}
+
+#[test]
+fn test_ordering() {
+    let values = [
+        "-2x10^3", "-1x10^3", "-5x10^-2", "0x10^7", "3x10^-4", "1x10^2", "9x10^2", "1x10^3",
+    ].map(|value| SciNote::parse_from_str(value).unwrap());
+
+    for window in values.windows(2) {
+        assert!(window[0] < window[1], "{} should be less than {}", window[0], window[1]);
+    }
+
+    // Values built from unnormalized coefficients are ordered by their value as well
+    let num1 = SciNote::build().coefficient(Decimal::from(150)).exponent(0).finish().unwrap();
+    let num2 = SciNote::parse_from_str("1x10^2").unwrap();
+    assert!(num1 > num2);
+}
+
+#[test]
+fn test_sort_and_dedup() {
+    let mut values = ["3x10^2", "30x10^1", "-1x10^0", "1x10^-1", "0.3x10^3"]
+        .map(|value| SciNote::parse_from_str(value).unwrap())
+        .to_vec();
+
+    values.sort();
+    values.dedup();
+
+    let expected = ["-1x10^0", "1x10^-1", "3x10^2"].map(|value| SciNote::parse_from_str(value).unwrap());
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn test_hash_and_map_keys() {
+    let num1 = SciNote::build().coefficient(Decimal::from(10)).exponent(0).finish().unwrap();
+    let num2 = SciNote::parse_from_str("1.0x10^1").unwrap().display_decimals(4);
+
+    let set = HashSet::from([num1, num2]);
+    assert_eq!(set.len(), 1);
+
+    let mut hash_map = HashMap::new();
+    hash_map.insert(num1, "first");
+    hash_map.insert(num2, "second");
+    assert_eq!(hash_map.len(), 1);
+    assert_eq!(hash_map[&num1], "second");
+
+    let mut btree_map = BTreeMap::new();
+    btree_map.insert(num1, "first");
+    btree_map.insert(SciNote::parse_from_str("-1x10^1").unwrap(), "negative");
+    assert_eq!(btree_map.get(&num2), Some(&"first"));
+    assert_eq!(btree_map.values().copied().collect::<Vec<_>>(), ["negative", "first"]);
+}
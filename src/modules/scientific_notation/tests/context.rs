@@ -0,0 +1,166 @@
+use crate::{RoundingMode, SciContext, SciError, SciNote, Signal};
+
+fn parse(input: &str) -> SciNote {
+    SciNote::parse_from_str(input).unwrap()
+}
+
+#[test]
+fn test_exact_operations_raise_no_flags() {
+    let mut context = SciContext::default().precision(5);
+
+    assert_eq!(parse("1.25x10^3").add_with_context(&parse("2.5x10^1"), &mut context).unwrap(), parse("1.275x10^3"));
+    assert_eq!(parse("1.2x10^3").sub_with_context(&parse("2x10^2"), &mut context).unwrap(), parse("1x10^3"));
+    assert_eq!(parse("1.2x10^3").mul_with_context(&parse("-3x10^-5"), &mut context).unwrap(), parse("-3.6x10^-2"));
+    assert_eq!(parse("1x10^0").div_with_context(&parse("4x10^0"), &mut context).unwrap(), parse("2.5x10^-1"));
+    assert_eq!(parse("7x10^-12").round_with_context(&mut context).unwrap(), parse("7x10^-12"));
+    assert!((parse("4.2x10^7").sub_with_context(&parse("4.2x10^7"), &mut context).unwrap()).is_zero());
+
+    assert!(context.flags().is_empty());
+}
+
+#[test]
+fn test_rounding_to_the_precision() {
+    let mut context = SciContext::default().precision(4);
+
+    let third = parse("1x10^0").div_with_context(&parse("3x10^0"), &mut context).unwrap();
+    assert_eq!(third, parse("3.333x10^-1"));
+    assert_eq!(context.flags(), vec![Signal::Inexact, Signal::Rounded]);
+
+    //  Discarding zeros rounds without losing precision
+    context.clear_flags();
+    let product = parse("1.50x10^0").mul_with_context(&parse("2.000x10^0"), &mut context).unwrap();
+    assert_eq!(product, parse("3x10^0"));
+    assert_eq!(context.flags(), vec![Signal::Rounded]);
+
+    //  The rounding mode of the context is applied once, on the exact result
+    let mut context = SciContext::default().precision(3).rounding(RoundingMode::Floor);
+    assert_eq!(parse("1x10^0").sub_with_context(&parse("1x10^-40"), &mut context).unwrap(), parse("9.99x10^-1"));
+    let mut context = SciContext::default().precision(3).rounding(RoundingMode::Ceiling);
+    assert_eq!(parse("1x10^0").sub_with_context(&parse("1x10^-40"), &mut context).unwrap(), parse("1x10^0"));
+    assert_eq!(parse("1x10^0").add_with_context(&parse("1x10^-40"), &mut context).unwrap(), parse("1.01x10^0"));
+
+    let mut context = SciContext::default().precision(2);
+    assert_eq!(parse("9.96x10^2").round_with_context(&mut context).unwrap(), parse("1.0x10^3"));
+    assert_eq!(parse("2.25x10^0").round_with_context(&mut context).unwrap(), parse("2.2x10^0"));
+
+    //  The precision is limited between 1 and 28 digits
+    let mut context = SciContext::default().precision(0);
+    assert_eq!(parse("2.25x10^0").round_with_context(&mut context).unwrap(), parse("2x10^0"));
+}
+
+#[test]
+fn test_flags_are_sticky() {
+    let mut context = SciContext::default().precision(6);
+    let mut total = SciNote::zero();
+    for price in ["1.99x10^0", "2.49x10^0", "1x10^0"] {
+        total = total.add_with_context(&parse(price), &mut context).unwrap();
+    }
+    assert!(context.flags().is_empty());
+
+    //  A single inexact step is remembered along the rest of the chain
+    let share = total.div_with_context(&parse("3x10^0"), &mut context).unwrap();
+    let total = share.mul_with_context(&parse("3x10^0"), &mut context).unwrap();
+    assert_eq!(total, parse("5.48001x10^0"));
+    assert!(context.is_flagged(Signal::Inexact));
+    assert!(context.is_flagged(Signal::Rounded));
+    assert!(!context.is_flagged(Signal::Overflow));
+
+    context.clear_flags();
+    assert!(context.flags().is_empty());
+}
+
+#[test]
+fn test_traps() {
+    //  Trapping Inexact points to the step where the rounding happened
+    let mut context = SciContext::default().precision(6).trap(Signal::Inexact);
+    let total = parse("5.48x10^0").mul_with_context(&parse("2x10^0"), &mut context).unwrap();
+    let Err(SciError::PrecisionError(Some(message))) = total.div_with_context(&parse("3x10^0"), &mut context) else {
+        panic!("expected a precision error")
+    };
+    assert_eq!(message, "Signal 'inexact' trapped in divide");
+    assert!(context.is_flagged(Signal::Inexact));
+
+    //  Rounded is trapped even when the discarded digits are zeros
+    let mut context = SciContext::default().precision(2).trap(Signal::Rounded);
+    assert!(matches!(parse("1.50x10^0").round_with_context(&mut context), Err(SciError::PrecisionError(_))));
+    assert_eq!(context.flags(), vec![Signal::Rounded]);
+
+    let context = SciContext::default().trap(Signal::Underflow).untrap(Signal::Overflow);
+    assert!(context.is_trapped(Signal::Underflow));
+    assert!(!context.is_trapped(Signal::Overflow));
+    assert!(SciContext::default().is_trapped(Signal::DivisionByZero));
+    assert!(!SciContext::default().is_trapped(Signal::Inexact));
+}
+
+#[test]
+fn test_exponent_bounds() {
+    let mut context = SciContext::default().precision(3).exponent_range(-10, 10);
+
+    //  Overflow is trapped by default
+    let Err(SciError::ExponentError(Some(message))) = parse("5x10^6").mul_with_context(&parse("2x10^4"), &mut context) else {
+        panic!("expected an exponent error")
+    };
+    assert_eq!(message, "Signal 'overflow' trapped in multiply");
+    assert_eq!(context.flags(), vec![Signal::Overflow, Signal::Inexact, Signal::Rounded]);
+
+    //  A rounding that carries past the maximum exponent overflows as well
+    context.clear_flags();
+    assert!(parse("9.999x10^10").round_with_context(&mut context).is_err());
+    assert!(context.is_flagged(Signal::Overflow));
+    assert_eq!(parse("9.99x10^10").round_with_context(&mut context).unwrap(), parse("9.99x10^10"));
+
+    //  Untrapped, an overflow gives the largest value of the context, and an underflow zero
+    let mut context = context.untrap(Signal::Overflow);
+    context.clear_flags();
+    assert_eq!(parse("-5x10^6").mul_with_context(&parse("2x10^4"), &mut context).unwrap(), parse("-9.99x10^10"));
+    assert!(parse("1x10^-6").mul_with_context(&parse("1x10^-6"), &mut context).unwrap().is_zero());
+    assert_eq!(context.flags(), vec![Signal::Overflow, Signal::Underflow, Signal::Inexact, Signal::Rounded]);
+
+    let mut context = context.trap(Signal::Underflow);
+    assert!(matches!(
+        parse("1x10^-6").div_with_context(&parse("1x10^6"), &mut context),
+        Err(SciError::ExponentError(_))
+    ));
+
+    //  The default bounds are the ones of SciNote, results out of them overflow instead of failing
+    let mut context = SciContext::default().untrap(Signal::Overflow);
+    let largest = SciNote::MAX.add_with_context(&SciNote::MAX, &mut context).unwrap();
+    assert_eq!(largest, SciNote::MAX);
+    assert!(context.is_flagged(Signal::Overflow));
+}
+
+#[test]
+#[should_panic]
+fn test_empty_exponent_range() {
+    let _ = SciContext::default().exponent_range(10, -10);
+}
+
+#[test]
+fn test_division_by_zero() {
+    let mut context = SciContext::default();
+    let Err(SciError::OperationError(Some(message))) = parse("1x10^0").div_with_context(&SciNote::zero(), &mut context) else {
+        panic!("expected an operation error")
+    };
+    assert_eq!(message, "Signal 'division by zero' trapped in divide");
+    assert_eq!(context.flags(), vec![Signal::DivisionByZero]);
+
+    let mut context = SciContext::default().precision(2).untrap(Signal::DivisionByZero);
+    assert_eq!(parse("-1x10^0").div_with_context(&SciNote::zero(), &mut context).unwrap(), parse("-9.9x10^32767"));
+    assert_eq!(context.flags(), vec![Signal::DivisionByZero]);
+
+    //  Zero divided by zero is undefined, whatever the traps are
+    assert!(matches!(
+        SciNote::zero().div_with_context(&SciNote::zero(), &mut context),
+        Err(SciError::OperationError(_))
+    ));
+}
+
+#[test]
+fn test_results_keep_display_settings() {
+    let mut context = SciContext::default().precision(4);
+    let product = parse("2x10^0")
+        .display_decimals(1)
+        .mul_with_context(&parse("3.14159x10^0"), &mut context)
+        .unwrap();
+    assert_eq!(product.to_string(), "6.3x10^0");
+}
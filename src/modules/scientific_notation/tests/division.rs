@@ -0,0 +1,153 @@
+use rust_decimal::Decimal;
+use crate::{BigSciNote, RoundingMode, SciError, SciNote};
+
+#[test]
+fn test_division_ok() {
+    let num1 = SciNote::parse_from_str("6x10^7").unwrap();
+    let num2 = SciNote::parse_from_str("3x10^4").unwrap();
+
+    let result = num1.div(&num2).unwrap();
+
+    assert_eq!(result.to_string(), "2x10^3".to_string());
+}
+
+#[test]
+fn test_division_normalizes_result() {
+    // 1e3 / 4e5 = 0.25e-2 = 2.5e-3
+    let num1 = SciNote::parse_from_str("1x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("4x10^5").unwrap();
+
+    let result = num1.div(&num2).unwrap();
+
+    assert_eq!(result.coefficient, Decimal::new(25, 1));
+    assert_eq!(result.exponent, -3);
+}
+
+#[test]
+fn test_division_negative_values() {
+    let num1 = SciNote::parse_from_str("-9x10^-3").unwrap();
+    let num2 = SciNote::parse_from_str("3x10^-5").unwrap();
+
+    let result = num1.div(&num2).unwrap();
+
+    assert_eq!(result, SciNote::parse_from_str("-3x10^2").unwrap());
+}
+
+#[test]
+fn test_division_default_precision() {
+    let num1 = SciNote::parse_from_str("1x10^0").unwrap();
+    let num2 = SciNote::parse_from_str("3x10^0").unwrap();
+
+    let result = num1.div(&num2).unwrap();
+
+    // 27 significant digits
+    assert_eq!(result.coefficient, Decimal::from_i128_with_scale(333333333333333333333333333, 26));
+    assert_eq!(result.exponent, -1);
+}
+
+#[test]
+fn test_division_selected_precision() {
+    let num1 = SciNote::parse_from_str("2x10^0").unwrap();
+    let num2 = SciNote::parse_from_str("3x10^0").unwrap();
+
+    let result = num1.div_with_precision(&num2, 4, RoundingMode::HalfEven).unwrap();
+    assert_eq!(result.coefficient, Decimal::new(6667, 3));
+    assert_eq!(result.exponent, -1);
+
+    let result = num1.div_with_precision(&num2, 4, RoundingMode::TowardZero).unwrap();
+    assert_eq!(result.coefficient, Decimal::new(6666, 3));
+}
+
+#[test]
+fn test_division_rounding_carries_into_new_digit() {
+    // 9.999 / 1 rounded to 2 significant digits is 10, normalized as 1.0e1
+    let num1 = SciNote::parse_from_str("9.999x10^0").unwrap();
+    let num2 = SciNote::parse_from_str("1x10^0").unwrap();
+
+    let result = num1.div_with_precision(&num2, 2, RoundingMode::HalfAwayFromZero).unwrap();
+
+    assert_eq!(result.coefficient, Decimal::from(1));
+    assert_eq!(result.exponent, 1);
+}
+
+#[test]
+fn test_division_invalid_precision() {
+    let num1 = SciNote::parse_from_str("1x10^0").unwrap();
+    let num2 = SciNote::parse_from_str("3x10^0").unwrap();
+
+    let result = num1.div_with_precision(&num2, 0, RoundingMode::HalfEven);
+    assert!(matches!(result, Err(SciError::PrecisionError(_))));
+
+    let result = num1.div_with_precision(&num2, 28, RoundingMode::HalfEven);
+    assert!(matches!(result, Err(SciError::PrecisionError(_))));
+}
+
+#[test]
+fn test_division_by_zero() {
+    let num1 = SciNote::parse_from_str("1x10^3").unwrap();
+    let zero = SciNote::parse_from_str("0x10^0").unwrap();
+
+    let result = num1.div(&zero);
+
+    assert!(matches!(result, Err(SciError::OperationError(_))));
+}
+
+#[test]
+fn test_division_exponent_overflow() {
+    let num1 = SciNote::parse_from_str("5x10^32000").unwrap();
+    let num2 = SciNote::parse_from_str("5x10^-1000").unwrap();
+
+    let result = num1.div(&num2);
+
+    assert!(matches!(result, Err(SciError::ExponentError(_))));
+}
+
+#[test]
+fn test_division_operator() {
+    let num1 = SciNote::parse_from_str("3x10^8").unwrap();
+    let num2 = SciNote::parse_from_str("1.5x10^-2").unwrap();
+
+    let result = num1 / num2;
+
+    assert_eq!(result, SciNote::parse_from_str("2x10^10").unwrap());
+}
+
+#[test]
+fn test_division_rounds_once() {
+    // Quotients whose digits past the 28th decide the rounding, checked against exact quotients
+    let num1 = SciNote::parse_from_str("3.123517201067077465166515629x10^0").unwrap();
+    let num2 = SciNote::parse_from_str("9.778983003182043721513848295x10^0").unwrap();
+    let result = num1.div_with_precision(&num2, 27, RoundingMode::TowardZero).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("3.19411251666016486297377564x10^-1").unwrap());
+
+    let num1 = SciNote::parse_from_str("2.567514311267428590392376038x10^0").unwrap();
+    let num2 = SciNote::parse_from_str("4.703501910849623781499631754x10^0").unwrap();
+    assert_eq!(num1.div(&num2).unwrap(), SciNote::parse_from_str("5.45872917654165888895494575x10^-1").unwrap());
+    assert_eq!(num1 / num2, SciNote::parse_from_str("5.45872917654165888895494575x10^-1").unwrap());
+}
+
+#[test]
+fn test_division_truncates_exactly() {
+    // A truncated quotient q of a / b must satisfy |q| * |b| <= |a| < (|q| + ulp) * |b|, checked with
+    // exact products. The operands are 28 digit coefficients from a linear congruential generator
+    let mut state = 0x2545f4914f6cdd1du64;
+    let mut next_coefficient = || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let high = u128::from(state >> 4) % 9 + 1;
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let low = u128::from(state) % 10u128.pow(18) * 10u128.pow(9) + u128::from(state >> 40) % 10u128.pow(9);
+        Decimal::from_i128_with_scale((high * 10u128.pow(27) + low) as i128, 27)
+    };
+
+    for _ in 0..500 {
+        let num1 = SciNote::build().coefficient(next_coefficient()).finish().unwrap();
+        let num2 = SciNote::build().coefficient(next_coefficient()).finish().unwrap();
+        let quotient = num1.div_with_precision(&num2, 27, RoundingMode::TowardZero).unwrap();
+
+        let ulp = SciNote::parse_from_str(&format!("1x10^{}", quotient.exponent - 26)).unwrap();
+        let lower = BigSciNote::from(quotient) * BigSciNote::from(num2);
+        let upper = (BigSciNote::from(quotient) + BigSciNote::from(ulp)) * BigSciNote::from(num2);
+        let dividend = BigSciNote::from(num1);
+        assert!(lower <= dividend && dividend < upper, "{:e} / {:e} = {:e}", num1, num2, quotient);
+    }
+}
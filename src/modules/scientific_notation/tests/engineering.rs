@@ -0,0 +1,83 @@
+use rust_decimal::Decimal;
+use crate::{Notation, SciNote};
+
+#[test]
+fn test_to_engineering() {
+    let cases = [
+        ("4.7x10^3", "4.7", 3),
+        ("4.7x10^4", "47", 3),
+        ("4.7x10^5", "470", 3),
+        ("1.20x10^5", "120", 3),
+        ("2.2x10^-7", "220", -9),
+        ("-3.3x10^-6", "-3.3", -6),
+        ("1.23456x10^-1", "123.456", -3),
+        ("6.022x10^23", "602.2", 21)
+    ];
+
+    for (input, coefficient, exponent) in cases {
+        let value = SciNote::parse_from_str(input).unwrap();
+        assert_eq!(value.to_engineering(), (coefficient.parse::<Decimal>().unwrap(), exponent), "input: {}", input);
+    }
+
+    assert_eq!(SciNote::zero().to_engineering(), (Decimal::ZERO, 0));
+}
+
+#[test]
+fn test_to_engineering_bounds() {
+    let (coefficient, exponent) = SciNote::MAX.to_engineering();
+    assert_eq!(exponent, 32766);
+    assert_eq!(coefficient, Decimal::from_i128_with_scale(9999999999999999999999999999, 26));
+
+    let smallest = SciNote::parse_from_str("1x10^-32768").unwrap();
+    assert_eq!(smallest.to_engineering(), (Decimal::from(10), -32769));
+}
+
+#[test]
+fn test_engineering_display() {
+    let value = SciNote::parse_from_str("4.7x10^4").unwrap().notation(Notation::Engineering);
+    assert_eq!(value.to_string(), "47x10^3");
+
+    let value = SciNote::parse_from_str("-2.2049x10^-7").unwrap().notation(Notation::Engineering);
+    assert_eq!(value.to_string(), "-220.49x10^-9");
+    assert_eq!(value.display_decimals(1).to_string(), "-220.5x10^-9");
+    assert_eq!(format!("{:.3}", value), "-220.490x10^-9");
+    assert_eq!(format!("{:e}", value), "-220.49e-9");
+
+    //  Switching back to scientific notation
+    assert_eq!(value.notation(Notation::Scientific).to_string(), "-2.20x10^-7");
+}
+
+#[test]
+fn test_engineering_significant_figures() {
+    let value = SciNote::parse_from_str("4.7x10^4").unwrap().notation(Notation::Engineering);
+
+    assert_eq!(value.significant_figures(4).to_string(), "47.00x10^3");
+    assert_eq!(value.significant_figures(2).to_string(), "47x10^3");
+    assert_eq!(value.significant_figures(1).to_string(), "50x10^3");
+
+    let value = SciNote::parse_from_str("9.996x10^5").unwrap().notation(Notation::Engineering);
+    assert_eq!(value.significant_figures(3).to_string(), "1.00x10^6");
+}
+
+#[test]
+fn test_engineering_rounding_carry() {
+    let value = SciNote::parse_from_str("9.99996x10^5").unwrap().notation(Notation::Engineering);
+
+    assert_eq!(value.to_string(), "1.00x10^6");
+    assert_eq!(format!("{:.1}", value), "1.0x10^6");
+    assert_eq!(format!("{:.4}", value), "999.9960x10^3");
+}
+
+#[test]
+fn test_parse_engineering() {
+    let value = SciNote::parse_from_str("220x10^-9").unwrap();
+    assert_eq!(value.coefficient, Decimal::new(220, 2));
+    assert_eq!(value.exponent, -7);
+
+    let value = SciNote::parse_from_str("47e3").unwrap();
+    assert_eq!(value, SciNote::parse_from_str("4.7x10^4").unwrap());
+
+    //  Engineering output parses back to the same value
+    let value = SciNote::parse_from_str("-1.23456x10^-10").unwrap().notation(Notation::Engineering);
+    assert_eq!(format!("{:e}", value).parse::<SciNote>().unwrap(), value);
+}
@@ -0,0 +1,56 @@
+use crate::{RoundingMode, SciNote};
+
+#[test]
+fn test_lower_exp() {
+    let value = SciNote::parse_from_str("6.022x10^23").unwrap();
+    assert_eq!(format!("{:e}", value), "6.022e23");
+
+    let value = SciNote::parse_from_str("-1.602176634x10^-19").unwrap();
+    assert_eq!(format!("{:e}", value), "-1.602176634e-19");
+
+    assert_eq!(format!("{:e}", SciNote::zero()), "0e0");
+}
+
+#[test]
+fn test_upper_exp() {
+    let value = SciNote::parse_from_str("6.022x10^23").unwrap();
+    assert_eq!(format!("{:E}", value), "6.022E23");
+
+    let value = SciNote::parse_from_str("-1.602176634x10^-19").unwrap();
+    assert_eq!(format!("{:E}", value), "-1.602176634E-19");
+}
+
+#[test]
+fn test_exp_precision() {
+    let value = SciNote::parse_from_str("1.602176634x10^-19").unwrap();
+
+    //  Like floats, the precision keeps trailing zeros
+    assert_eq!(format!("{:.3e}", value), "1.602e-19");
+    assert_eq!(format!("{:.0E}", value), "2E-19");
+    assert_eq!(format!("{:.12e}", value), "1.602176634000e-19");
+    assert_eq!(format!("{:.3e}", 1.602176634e-19_f64), "1.602e-19");
+
+    //  Rounding carries into the exponent
+    let value = SciNote::parse_from_str("9.9996x10^9").unwrap();
+    assert_eq!(format!("{:.2e}", value), "1.00e10");
+    assert_eq!(format!("{:.2e}", value.rounding(RoundingMode::Floor)), "9.99e9");
+}
+
+#[test]
+fn test_exp_display_settings() {
+    let value = SciNote::parse_from_str("6.02214076x10^23").unwrap();
+
+    assert_eq!(format!("{:e}", value.display_decimals(2)), "6.02e23");
+    assert_eq!(format!("{:E}", value.significant_figures(6)), "6.02214E23");
+    assert_eq!(format!("{:.1e}", value.significant_figures(6)), "6.0e23");
+}
+
+#[test]
+fn test_exp_flags() {
+    let value = SciNote::parse_from_str("6.022x10^23").unwrap();
+
+    assert_eq!(format!("{:+e}", value), "+6.022e23");
+    assert_eq!(format!("{:>12e}|", value), "    6.022e23|");
+    assert_eq!(format!("{:<12.1E}|", -value), "-6.0E23     |");
+    assert_eq!(format!("{:010.2e}", -value), "-006.02e23");
+}
@@ -0,0 +1,91 @@
+use rust_decimal::Decimal;
+use crate::{SciError, SciNote};
+
+#[test]
+fn from_f64_exact_values() {
+    let value = SciNote::try_from(6.25e3f64).unwrap();
+    assert_eq!(value.coefficient, Decimal::new(625, 2));
+    assert_eq!(value.exponent, 3);
+
+    let value = SciNote::try_from(-0.5f64).unwrap();
+    assert_eq!(value.coefficient, Decimal::from(-5));
+    assert_eq!(value.exponent, -1);
+
+    // 2^-30 = 9.31322574615478515625e-10, exactly representable with 21 digits
+    let value = SciNote::try_from(2f64.powi(-30)).unwrap();
+    assert_eq!(value.coefficient, Decimal::from_i128_with_scale(931322574615478515625, 20));
+    assert_eq!(value.exponent, -10);
+}
+
+#[test]
+fn from_f64_rounds_to_decimal_precision() {
+    // The binary value of 0.1 is 0.1000000000000000055511151231257827..., rounded to 28 digits
+    let value = SciNote::try_from(0.1f64).unwrap();
+    assert_eq!(value.coefficient, Decimal::from_i128_with_scale(1000000000000000055511151231, 27));
+    assert_eq!(value.exponent, -1);
+}
+
+#[test]
+fn from_f64_extreme_values() {
+    let value = SciNote::try_from(f64::MAX).unwrap();
+    assert_eq!(value.exponent, 308);
+    assert_eq!(value.to_f64(), f64::MAX);
+
+    let value = SciNote::try_from(f64::MIN_POSITIVE).unwrap();
+    assert_eq!(value.exponent, -308);
+    assert_eq!(value.to_f64(), f64::MIN_POSITIVE);
+
+    // Smallest subnormal
+    let value = SciNote::try_from(5e-324f64).unwrap();
+    assert_eq!(value.exponent, -324);
+    assert_eq!(value.to_f64(), 5e-324f64);
+}
+
+#[test]
+fn from_f64_zero() {
+    assert!(SciNote::try_from(0.0f64).unwrap().is_zero());
+    assert!(SciNote::try_from(-0.0f64).unwrap().is_zero());
+}
+
+#[test]
+fn from_f64_rejects_non_finite() {
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(matches!(SciNote::try_from(value), Err(SciError::ConversionError(_))));
+    }
+    for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        assert!(matches!(SciNote::try_from(value), Err(SciError::ConversionError(_))));
+    }
+}
+
+#[test]
+fn from_f32_exact_values() {
+    let value = SciNote::try_from(1.5e-3f32).unwrap();
+    assert_eq!(value.to_f32(), 1.5e-3f32);
+
+    let value = SciNote::try_from(-3.0e38f32).unwrap();
+    assert_eq!(value.exponent, 38);
+    assert_eq!(value.to_f32(), -3.0e38f32);
+}
+
+#[test]
+fn to_f64_beyond_decimal_range() {
+    assert_eq!(SciNote::parse_from_str("1x10^300").unwrap().to_f64(), 1e300);
+    assert_eq!(SciNote::parse_from_str("1x10^-300").unwrap().to_f64(), 1e-300);
+    assert_eq!(SciNote::parse_from_str("-6.02214076x10^23").unwrap().to_f64(), -6.02214076e23);
+    assert_eq!(SciNote::parse_from_str("1.602176634x10^-19").unwrap().to_f64(), 1.602176634e-19);
+}
+
+#[test]
+fn to_f64_out_of_float_range() {
+    assert_eq!(SciNote::parse_from_str("1x10^400").unwrap().to_f64(), f64::INFINITY);
+    assert_eq!(SciNote::parse_from_str("-1x10^400").unwrap().to_f64(), f64::NEG_INFINITY);
+    assert_eq!(SciNote::parse_from_str("1x10^-400").unwrap().to_f64(), 0.0);
+    assert_eq!(SciNote::zero().to_f64(), 0.0);
+}
+
+#[test]
+fn f64_round_trip() {
+    for value in [1.0f64, -2.5e-7, 6.02214076e23, 1.0 / 3.0, 123456789.125, 9.999999999e99] {
+        assert_eq!(SciNote::try_from(value).unwrap().to_f64(), value);
+    }
+}
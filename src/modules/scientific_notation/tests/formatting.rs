@@ -0,0 +1,68 @@
+use crate::{Notation, RoundingMode, SciNote};
+
+#[test]
+fn test_format_precision() {
+    let value = SciNote::parse_from_str("6.02214076x10^23").unwrap();
+
+    assert_eq!(format!("{:.4}", value), "6.0221x10^23");
+    assert_eq!(format!("{:.0}", value), "6x10^23");
+    assert_eq!(format!("{:.10}", value), "6.0221407600x10^23");
+
+    //  The precision overrides the display decimals and significant figures
+    assert_eq!(format!("{:.1}", value.display_decimals(5)), "6.0x10^23");
+    assert_eq!(format!("{:.1}", value.significant_figures(5)), "6.0x10^23");
+
+    //  And it's rounded with the rounding mode, rolling over into the exponent
+    let value = SciNote::parse_from_str("9.96x10^-2").unwrap();
+    assert_eq!(format!("{:.1}", value), "1.0x10^-1");
+    assert_eq!(format!("{:.1}", value.rounding(RoundingMode::TowardZero)), "9.9x10^-2");
+}
+
+#[test]
+fn test_format_precision_past_the_decimal_places() {
+    //  A Decimal holds 28 decimals, the rest are zeros like in floats
+    let value = SciNote::parse_from_str("6.02214076x10^23").unwrap();
+    assert_eq!(format!("{:.100}", value), format!("6.02214076{}x10^23", "0".repeat(92)));
+    assert_eq!(format!("{:.30e}", value), format!("6.02214076{}e23", "0".repeat(22)));
+    assert_eq!(format!("{:.30}", value.notation(Notation::Engineering)), format!("602.214076{}x10^21", "0".repeat(24)));
+
+    let value = SciNote::parse_from_str("-1.2345678901234567890123456789x10^-3").unwrap();
+    assert_eq!(format!("{:.29}", value), "-1.23456789012345678901234567890x10^-3");
+    assert_eq!(format!("{:>+40.30}", SciNote::parse_from_str("5x10^0").unwrap()), format!("  +5.{}x10^0", "0".repeat(30)));
+}
+
+#[test]
+fn test_format_width_and_alignment() {
+    let value = SciNote::parse_from_str("-1.5x10^3").unwrap();
+
+    assert_eq!(format!("{:12}", value), "   -1.5x10^3");
+    assert_eq!(format!("{:>12}", value), "   -1.5x10^3");
+    assert_eq!(format!("{:<12}|", value), "-1.5x10^3   |");
+    assert_eq!(format!("{:^12}|", value), " -1.5x10^3  |");
+    assert_eq!(format!("{:*^14.2}", value), "**-1.50x10^3**");
+    assert_eq!(format!("{:012}", value), "-0001.5x10^3");
+
+    //  A width smaller than the notation doesn't truncate it
+    assert_eq!(format!("{:3}", value), "-1.5x10^3");
+}
+
+#[test]
+fn test_format_sign() {
+    let positive = SciNote::parse_from_str("2.5x10^-8").unwrap();
+    let negative = SciNote::parse_from_str("-2.5x10^-8").unwrap();
+
+    assert_eq!(format!("{:+}", positive), "+2.5x10^-8");
+    assert_eq!(format!("{:+}", negative), "-2.5x10^-8");
+    assert_eq!(format!("{:+}", SciNote::zero()), "+0x10^0");
+    assert_eq!(format!("{:+012.1}", positive), "+002.5x10^-8");
+    assert_eq!(format!("{:>+10.0}", positive), "  +2x10^-8");
+}
+
+#[test]
+fn test_format_table_columns() {
+    let values = ["1.5x10^3", "-2.25x10^-12", "9.1x10^100"]
+        .map(|value| SciNote::parse_from_str(value).unwrap());
+
+    let rows = values.iter().map(|value| format!("|{:>14.3}|", value)).collect::<Vec<String>>();
+    assert_eq!(rows, ["|    1.500x10^3|", "| -2.250x10^-12|", "|  9.100x10^100|"]);
+}
@@ -0,0 +1,107 @@
+use rust_decimal::Decimal;
+use crate::{SciError, SciNote};
+
+// ---- POSITIVE BIG NUMBERS ----
+#[test]
+fn positive_big_from_str() {
+    let sci_notation = "5.1237514651x10^38".parse::<SciNote>().unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::new(51237514651, 10));
+    assert_eq!(sci_notation.exponent, 38i16);
+    assert_eq!(sci_notation.display_decimals, None);
+}
+
+#[test]
+fn positive_big_try_from_str() {
+    let sci_notation = SciNote::try_from("3x10^8").unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::from(3));
+    assert_eq!(sci_notation.exponent, 8i16);
+}
+
+#[test]
+fn positive_big_try_from_string() {
+    let input = String::from("315.2x10^14");
+    let sci_notation = SciNote::try_from(input).unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::new(3152, 3));
+    assert_eq!(sci_notation.exponent, 16i16);
+}
+
+#[test]
+fn positive_big_from_str_with_error_invalid_exponent() {
+    assert!("2x10^23.4".parse::<SciNote>().is_err());
+    assert!(SciNote::try_from("2x10^23.4").is_err());
+    assert!(SciNote::try_from("2x10^23.4".to_string()).is_err());
+}
+
+// ---- POSITIVE SMALL NUMBERS ----
+#[test]
+fn positive_small_from_str() {
+    let sci_notation = "5.1237514651x10^-38".parse::<SciNote>().unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::new(51237514651, 10));
+    assert_eq!(sci_notation.exponent, -38i16);
+}
+
+#[test]
+fn positive_small_try_from_str_e_notation() {
+    let sci_notation = SciNote::try_from("1.6e-19").unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::new(16, 1));
+    assert_eq!(sci_notation.exponent, -19i16);
+}
+
+// ---- NEGATIVE BIG NUMBERS ----
+#[test]
+fn negative_big_from_str() {
+    let sci_notation = "-5.1237514651x10^38".parse::<SciNote>().unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::new(-51237514651, 10));
+    assert_eq!(sci_notation.exponent, 38i16);
+}
+
+#[test]
+fn negative_big_try_from_string_i64_min_mantissa() {
+    let sci_notation = SciNote::try_from("-9.223372036854775807x10^5".to_string()).unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::new(-9223372036854775807, 18));
+    assert_eq!(sci_notation.exponent, 5i16);
+}
+
+// ---- NEGATIVE SMALL NUMBERS ----
+#[test]
+fn negative_small_from_str() {
+    let sci_notation = "-5x10^-15".parse::<SciNote>().unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::from(-5));
+    assert_eq!(sci_notation.exponent, -15i16);
+    assert_eq!(sci_notation.display_decimals, None);
+}
+
+#[test]
+fn negative_small_try_from_str_with_error_invalid_coefficient() {
+    let sci_notation = SciNote::try_from("-2ax10^-20");
+
+    assert!(matches!(sci_notation, Err(SciError::ParseError(_))));
+}
+
+#[test]
+fn negative_small_from_str_and_display_back() {
+    let sci_notation = "-3.15x10^-14".parse::<SciNote>().unwrap();
+
+    assert_eq!(sci_notation.to_string(), "-3.15x10^-14".to_string());
+}
+
+// ---- GENERIC CODE ----
+fn parse_all<T: std::str::FromStr>(inputs: &[&str]) -> Result<Vec<T>, T::Err> {
+    inputs.iter().map(|input| input.parse::<T>()).collect()
+}
+
+#[test]
+fn from_str_in_generic_code() {
+    let values = parse_all::<SciNote>(&["1x10^3", "2e3", "0"]).unwrap();
+
+    assert_eq!(values, ["1x10^3", "2x10^3", "0x10^0"].map(|input| SciNote::parse_from_str(input).unwrap()));
+    assert!(parse_all::<SciNote>(&["1x10^3", "oops"]).is_err());
+}
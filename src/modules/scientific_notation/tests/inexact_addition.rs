@@ -0,0 +1,84 @@
+use rust_decimal::Decimal;
+use crate::SciNote;
+
+fn parse(input: &str) -> SciNote {
+    SciNote::parse_from_str(input).unwrap()
+}
+
+#[test]
+fn test_exact_addition() {
+    let (result, inexact) = parse("1.5x10^3").add_with_inexact(&parse("2.5x10^2")).unwrap();
+    assert_eq!(result, parse("1.75x10^3"));
+    assert!(!inexact);
+
+    let (result, inexact) = parse("1x10^0").add_with_inexact(&parse("1x10^-28")).unwrap();
+    assert_eq!(result.coefficient, Decimal::from_i128_with_scale(10000000000000000000000000001, 28));
+    assert!(!inexact);
+
+    let (result, inexact) = SciNote::zero().add_with_inexact(&parse("-4x10^-300")).unwrap();
+    assert_eq!(result, parse("-4x10^-300"));
+    assert!(!inexact);
+}
+
+#[test]
+fn test_far_apart_operands_are_absorbed() {
+    let (result, inexact) = parse("1x10^30000").add_with_inexact(&parse("1x10^-30000")).unwrap();
+    assert_eq!(result, parse("1x10^30000"));
+    assert!(inexact);
+
+    let (result, inexact) = parse("-1x10^-30000").add_with_inexact(&parse("1x10^30000")).unwrap();
+    assert_eq!(result, parse("1x10^30000"));
+    assert!(inexact);
+
+    assert_eq!(SciNote::MAX + SciNote::MIN.saturating_div(&parse("1x10^32767")), SciNote::MAX);
+}
+
+#[test]
+fn test_digits_below_precision_are_rounded() {
+    //  Past the 28 decimals of the bigger operand, ties are rounded to even
+    let (result, inexact) = parse("1x10^0").add_with_inexact(&parse("6x10^-29")).unwrap();
+    assert_eq!(result.coefficient, Decimal::from_i128_with_scale(10000000000000000000000000001, 28));
+    assert!(inexact);
+
+    let (result, inexact) = parse("1x10^0").add_with_inexact(&parse("5x10^-29")).unwrap();
+    assert_eq!(result, parse("1x10^0"));
+    assert!(inexact);
+
+    let (result, inexact) = parse("1x10^0").add_with_inexact(&parse("1.5x10^-28")).unwrap();
+    assert_eq!(result.coefficient, Decimal::from_i128_with_scale(10000000000000000000000000002, 28));
+    assert!(inexact);
+
+    let (result, inexact) = parse("-1x10^0").add_with_inexact(&parse("6x10^-29")).unwrap();
+    assert_eq!(result.coefficient, Decimal::from_i128_with_scale(-9999999999999999999999999999, 27));
+    assert_eq!(result.exponent, -1);
+    assert!(inexact);
+}
+
+#[test]
+fn test_rounded_coefficient_sum() {
+    //  The sum needs one more digit than the mantissa of a Decimal holds
+    let widest = SciNote::build().coefficient(Decimal::from_i128_with_scale(79228162514264337593543950333, 28)).finish().unwrap();
+
+    let (result, inexact) = widest.add_with_inexact(&widest).unwrap();
+    assert_eq!(result.coefficient, Decimal::from_i128_with_scale(15845632502852867518708790067, 28));
+    assert_eq!(result.exponent, 1);
+    assert!(inexact);
+
+    //  Unless the dropped digit is a zero
+    let widest = SciNote::build().coefficient(Decimal::from_i128_with_scale(79228162514264337593543950335, 28)).finish().unwrap();
+
+    let (result, inexact) = widest.add_with_inexact(&widest).unwrap();
+    assert_eq!(result.coefficient, Decimal::from_i128_with_scale(15845632502852867518708790067, 28));
+    assert!(!inexact);
+}
+
+#[test]
+fn test_sub_with_inexact() {
+    let (result, inexact) = parse("3x10^2").sub_with_inexact(&parse("5x10^1")).unwrap();
+    assert_eq!(result, parse("2.5x10^2"));
+    assert!(!inexact);
+
+    let (result, inexact) = parse("1x10^5").sub_with_inexact(&parse("1x10^-40")).unwrap();
+    assert_eq!(result, parse("1x10^5"));
+    assert!(inexact);
+}
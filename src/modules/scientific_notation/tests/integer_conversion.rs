@@ -0,0 +1,121 @@
+use rust_decimal::Decimal;
+use crate::{SciError, SciNote};
+
+#[test]
+fn from_signed_integers() {
+    assert_eq!(SciNote::from(-5i8), SciNote::parse_from_str("-5x10^0").unwrap());
+    assert_eq!(SciNote::from(1200i16), SciNote::parse_from_str("1.2x10^3").unwrap());
+    assert_eq!(SciNote::from(i32::MIN), SciNote::parse_from_str("-2.147483648x10^9").unwrap());
+    assert_eq!(SciNote::from(i64::MAX), SciNote::parse_from_str("9.223372036854775807x10^18").unwrap());
+    assert!(SciNote::from(0i64).is_zero());
+}
+
+#[test]
+fn from_unsigned_integers() {
+    assert_eq!(SciNote::from(255u8), SciNote::parse_from_str("2.55x10^2").unwrap());
+    assert_eq!(SciNote::from(60000u16), SciNote::parse_from_str("6x10^4").unwrap());
+    assert_eq!(SciNote::from(u32::MAX), SciNote::parse_from_str("4.294967295x10^9").unwrap());
+    assert_eq!(SciNote::from(u64::MAX), SciNote::parse_from_str("1.8446744073709551615x10^19").unwrap());
+}
+
+#[test]
+fn from_integers_is_normalized() {
+    let value = SciNote::from(5000u32);
+
+    assert_eq!(value.coefficient, Decimal::from(5));
+    assert_eq!(value.exponent, 3);
+}
+
+#[test]
+fn from_128_bit_integers() {
+    // Trailing zeros don't count against the Decimal precision
+    let value = SciNote::try_from(10u128.pow(38)).unwrap();
+    assert_eq!(value, SciNote::parse_from_str("1x10^38").unwrap());
+    assert_eq!(u128::try_from(value).unwrap(), 10u128.pow(38));
+
+    // Up to 2^96 - 1 the mantissa is kept whole
+    let value = SciNote::try_from((1u128 << 96) - 1).unwrap();
+    assert_eq!(u128::try_from(value).unwrap(), (1u128 << 96) - 1);
+
+    let value = SciNote::try_from(-((1i128 << 96) - 1) * 1000).unwrap();
+    assert_eq!(i128::try_from(value).unwrap(), -((1i128 << 96) - 1) * 1000);
+
+    // Bigger mantissas aren't rounded, they fail
+    assert!(matches!(SciNote::try_from(u128::MAX), Err(SciError::PrecisionError(_))));
+    assert!(matches!(SciNote::try_from(i128::MIN), Err(SciError::PrecisionError(_))));
+    assert!(matches!(SciNote::try_from(1u128 << 96), Err(SciError::PrecisionError(_))));
+}
+
+#[test]
+fn to_integers() {
+    let value = SciNote::parse_from_str("1.2x10^3").unwrap();
+
+    assert_eq!(i16::try_from(value).unwrap(), 1200);
+    assert_eq!(u64::try_from(value).unwrap(), 1200);
+    assert_eq!(i128::try_from(-value).unwrap(), -1200);
+    assert_eq!(u8::try_from(SciNote::zero()).unwrap(), 0);
+}
+
+#[test]
+fn to_integers_bounds() {
+    assert_eq!(i8::try_from(SciNote::from(i8::MIN)).unwrap(), i8::MIN);
+    assert_eq!(i64::try_from(SciNote::from(i64::MIN)).unwrap(), i64::MIN);
+    assert_eq!(u64::try_from(SciNote::from(u64::MAX)).unwrap(), u64::MAX);
+    assert_eq!(i128::try_from(SciNote::parse_from_str("-1.7x10^38").unwrap()).unwrap(), -17 * 10i128.pow(37));
+}
+
+#[test]
+fn to_integers_out_of_range() {
+    let big = SciNote::parse_from_str("3x10^2").unwrap();
+    assert!(matches!(u8::try_from(big), Err(SciError::OutOfRangeError(_))));
+    assert!(matches!(i8::try_from(-big), Err(SciError::OutOfRangeError(_))));
+    assert!(matches!(u32::try_from(-big), Err(SciError::OutOfRangeError(_))));
+    assert!(matches!(u128::try_from(-big), Err(SciError::OutOfRangeError(_))));
+
+    let huge = SciNote::parse_from_str("1x10^39").unwrap();
+    assert!(matches!(u128::try_from(huge), Err(SciError::OutOfRangeError(_))));
+    assert!(matches!(i128::try_from(huge), Err(SciError::OutOfRangeError(_))));
+    assert!(matches!(u64::try_from(SciNote::MAX), Err(SciError::OutOfRangeError(_))));
+}
+
+#[test]
+fn to_integers_with_fractional_part() {
+    for input in ["1.5x10^0", "1.25x10^1", "5x10^-1", "-3x10^-20"] {
+        let value = SciNote::parse_from_str(input).unwrap();
+        assert!(matches!(i64::try_from(value), Err(SciError::PrecisionError(_))), "{}", input);
+    }
+}
+
+#[test]
+fn from_decimal() {
+    let value = SciNote::from(Decimal::new(-31415, 4));
+    assert_eq!(value, SciNote::parse_from_str("-3.1415x10^0").unwrap());
+
+    let value = SciNote::from(Decimal::new(25, 6));
+    assert_eq!(value, SciNote::parse_from_str("2.5x10^-5").unwrap());
+
+    let value = SciNote::from(Decimal::MAX);
+    assert_eq!(value, SciNote::parse_from_str("7.9228162514264337593543950335x10^28").unwrap());
+}
+
+#[test]
+fn to_decimal() {
+    let value = SciNote::parse_from_str("1.5x10^-3").unwrap();
+    assert_eq!(Decimal::try_from(value).unwrap(), Decimal::new(15, 4));
+
+    let value = SciNote::parse_from_str("-1.5x10^20").unwrap();
+    assert_eq!(Decimal::try_from(value).unwrap(), Decimal::from_i128_with_scale(-150000000000000000000, 0));
+
+    assert_eq!(Decimal::try_from(SciNote::from(Decimal::MAX)).unwrap(), Decimal::MAX);
+    assert_eq!(Decimal::try_from(SciNote::from(Decimal::MIN)).unwrap(), Decimal::MIN);
+    assert_eq!(Decimal::try_from(SciNote::zero()).unwrap(), Decimal::ZERO);
+}
+
+#[test]
+fn to_decimal_out_of_range() {
+    let value = SciNote::parse_from_str("8x10^28").unwrap();
+    assert!(matches!(Decimal::try_from(value), Err(SciError::OutOfRangeError(_))));
+
+    let value = SciNote::parse_from_str("1x10^-29").unwrap();
+    assert!(matches!(Decimal::try_from(value), Err(SciError::PrecisionError(_))));
+}
@@ -3,4 +3,27 @@ mod positive_big_numbers;
 mod positive_small_numbers;
 mod negative_small_numbers;
 mod sum;
-mod scale_by_shift;
\ No newline at end of file
+mod scale_by_shift;
+mod subtraction;
+mod multiplication;
+mod division;
+mod operators;
+mod overflow;
+mod comparison;
+mod zero;
+mod parse_syntaxes;
+mod from_str;
+mod parse_errors;
+mod float_conversion;
+mod integer_conversion;
+mod significant_figures;
+mod rounding;
+mod formatting;
+mod exp_formatting;
+mod engineering;
+mod si_prefix;
+mod renderers;
+mod inexact_addition;
+mod big_sci_note;
+mod wide_sci_note;
+mod context;
@@ -0,0 +1,91 @@
+use rust_decimal::Decimal;
+use crate::{SciError, SciNote};
+
+#[test]
+fn test_multiplication_ok() {
+    let num1 = SciNote::parse_from_str("2x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("3x10^4").unwrap();
+
+    let result = num1.mul(&num2).unwrap();
+
+    assert_eq!(result.to_string(), "6x10^7".to_string());
+}
+
+#[test]
+fn test_multiplication_normalizes_result() {
+    // 5e3 * 4e2 = 20e5 = 2e6
+    let num1 = SciNote::parse_from_str("5x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("4x10^2").unwrap();
+
+    let result = num1.mul(&num2).unwrap();
+
+    assert_eq!(result.coefficient, Decimal::from(2));
+    assert_eq!(result.exponent, 6);
+}
+
+#[test]
+fn test_multiplication_negative_exponents_and_signs() {
+    // -2.5e-3 * 4e-5 = -1e-7
+    let num1 = SciNote::parse_from_str("-2.5x10^-3").unwrap();
+    let num2 = SciNote::parse_from_str("4x10^-5").unwrap();
+    let result = num1.mul(&num2).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("-1x10^-7").unwrap());
+
+    // -2.5e-3 * -2e3 = 5e0
+    let num3 = SciNote::parse_from_str("-2x10^3").unwrap();
+    let result = num1.mul(&num3).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("5x10^0").unwrap());
+}
+
+#[test]
+fn test_multiplication_keeps_precision() {
+    // 1.23456789e3 * 2e2 = 2.46913578e5
+    let num1 = SciNote::parse_from_str("1.23456789x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^2").unwrap();
+
+    let result = num1.mul(&num2).unwrap();
+
+    assert_eq!(result, SciNote::parse_from_str("2.46913578x10^5").unwrap());
+}
+
+#[test]
+fn test_multiplication_exponent_overflow() {
+    let num1 = SciNote::parse_from_str("5x10^32000").unwrap();
+    let num2 = SciNote::parse_from_str("5x10^1000").unwrap();
+
+    let result = num1.mul(&num2);
+
+    assert!(matches!(result, Err(SciError::ExponentError(_))));
+}
+
+#[test]
+fn test_multiplication_exponent_underflow() {
+    let num1 = SciNote::parse_from_str("5x10^-32000").unwrap();
+    let num2 = SciNote::parse_from_str("5x10^-1000").unwrap();
+
+    let result = num1.mul(&num2);
+
+    assert!(matches!(result, Err(SciError::ExponentError(_))));
+}
+
+#[test]
+fn test_multiplication_scaling_back_into_range() {
+    // 5e-32768 * 2e-1 = 10e-32769, the raw exponent sum is below i16::MIN but scaling fits it back
+    let num1 = SciNote::parse_from_str("5x10^-32768").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^-1").unwrap();
+
+    let result = num1.mul(&num2).unwrap();
+
+    assert_eq!(result.coefficient, Decimal::from(1));
+    assert_eq!(result.exponent, i16::MIN);
+}
+
+#[test]
+fn test_multiplication_operator() {
+    let num1 = SciNote::parse_from_str("3x10^8").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^-2").unwrap();
+
+    let result = num1 * num2;
+
+    assert_eq!(result, SciNote::parse_from_str("6x10^6").unwrap());
+}
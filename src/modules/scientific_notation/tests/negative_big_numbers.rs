@@ -1,5 +1,6 @@
 use rust_decimal::Decimal;
 use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::{ParsePart, SciError};
 
 // ---- NEGATIVE BIG NUMBERS ----
 #[test]
@@ -59,7 +60,7 @@ fn negative_big_parse_and_display_back() {
     let input = "-315.2x10^14";
     let sci_notation = SciNote::parse_from_str(input).unwrap();
 
-    assert_eq!(sci_notation.to_string(), input.to_string());
+    assert_eq!(sci_notation.to_string(), "-3.15x10^16".to_string());
 }
 
 #[test]
@@ -78,9 +79,11 @@ fn negative_big_from_builder_into_string() {
     let sci_notation = SciNote::build()
         .coefficient(Decimal::new(-6278964, 5))
         .exponent(20i16)
+        .finish()
+        .unwrap()
         .display_decimals(3);
 
-    assert_eq!(sci_notation.to_string(), "-62.790x10^20".to_string());
+    assert_eq!(sci_notation.to_string(), "-6.279x10^21".to_string());
 }
 
 #[test]
@@ -97,12 +100,20 @@ fn negative_big_parse_i64_min_mantissa() {
 #[test]
 fn negative_big_parse_max_exponent(){
     let max_exponent = i16::MAX.to_string();
-    let input = format!("-15.321x10^{}", max_exponent);
+    let input = format!("-1.5321x10^{}", max_exponent);
     let sci_notation = SciNote::parse_from_str(&input);
 
     assert!(sci_notation.is_ok());
     let result = sci_notation.unwrap();
-    assert_eq!(result.coefficient, Decimal::new(-15321, 3));
+    assert_eq!(result.coefficient, Decimal::new(-15321, 4));
     assert_eq!(result.exponent, i16::MAX);
 }
 
+#[test]
+fn negative_big_parse_max_exponent_normalized_out_of_range(){
+    let max_exponent = i16::MAX.to_string();
+    let input = format!("-15.321x10^{}", max_exponent);
+    let sci_notation = SciNote::parse_from_str(&input);
+
+    assert!(matches!(sci_notation, Err(SciError::ParseError(error)) if error.part() == ParsePart::Exponent));
+}
@@ -59,7 +59,7 @@ fn negative_small_parse_and_display_back() {
     let input = "-315.2x10^-14";
     let sci_notation = SciNote::parse_from_str(input).unwrap();
 
-    assert_eq!(sci_notation.to_string(), input.to_string());
+    assert_eq!(sci_notation.to_string(), "-3.15x10^-12".to_string());
 }
 
 #[test]
@@ -92,7 +92,7 @@ fn negative_small_parse_max_exponent(){
 
     assert!(sci_notation.is_ok());
     let result = sci_notation.unwrap();
-    assert_eq!(result.coefficient, Decimal::new(-15321, 3));
-    assert_eq!(result.exponent, i16::MIN);
+    assert_eq!(result.coefficient, Decimal::new(-15321, 4));
+    assert_eq!(result.exponent, i16::MIN + 1);
 }
 
@@ -0,0 +1,78 @@
+//  Borrowed operands are used on purpose, to exercise every operator combination
+#![allow(clippy::op_ref)]
+
+use crate::SciNote;
+
+#[test]
+fn test_operators_owned_and_borrowed() {
+    let num1 = SciNote::parse_from_str("6x10^4").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^2").unwrap();
+
+    assert_eq!(num1 + num2, SciNote::parse_from_str("6.02x10^4").unwrap());
+    assert_eq!(&num1 + &num2, SciNote::parse_from_str("6.02x10^4").unwrap());
+    assert_eq!(num1 - &num2, SciNote::parse_from_str("5.98x10^4").unwrap());
+    assert_eq!(&num1 - num2, SciNote::parse_from_str("5.98x10^4").unwrap());
+    assert_eq!(&num1 * &num2, SciNote::parse_from_str("1.2x10^7").unwrap());
+    assert_eq!(num1 * &num2, SciNote::parse_from_str("1.2x10^7").unwrap());
+    assert_eq!(&num1 / &num2, SciNote::parse_from_str("3x10^2").unwrap());
+    assert_eq!(&num1 / num2, SciNote::parse_from_str("3x10^2").unwrap());
+}
+
+#[test]
+fn test_operators_chained() {
+    // (2e3 + 3e3) * 4e-1 / 2e0 - 1e3 = 0
+    let num1 = SciNote::parse_from_str("2x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("3x10^3").unwrap();
+    let num3 = SciNote::parse_from_str("4x10^-1").unwrap();
+    let num4 = SciNote::parse_from_str("2x10^0").unwrap();
+    let num5 = SciNote::parse_from_str("1x10^3").unwrap();
+
+    let result = (num1 + num2) * num3 / num4 - num5;
+
+    assert!(result.coefficient.is_zero());
+}
+
+#[test]
+fn test_assign_operators() {
+    let mut result = SciNote::parse_from_str("1x10^3").unwrap();
+    let num = SciNote::parse_from_str("5x10^2").unwrap();
+
+    result += num;
+    assert_eq!(result, SciNote::parse_from_str("1.5x10^3").unwrap());
+
+    result -= &num;
+    assert_eq!(result, SciNote::parse_from_str("1x10^3").unwrap());
+
+    result *= num;
+    assert_eq!(result, SciNote::parse_from_str("5x10^5").unwrap());
+
+    result /= &num;
+    assert_eq!(result, SciNote::parse_from_str("1x10^3").unwrap());
+}
+
+#[test]
+fn test_negation() {
+    let num = SciNote::parse_from_str("6.022x10^23").unwrap();
+
+    assert_eq!(-num, SciNote::parse_from_str("-6.022x10^23").unwrap());
+    assert_eq!(-&num, SciNote::parse_from_str("-6.022x10^23").unwrap());
+    assert_eq!(-(-num), num);
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply failed")]
+fn test_operator_exponent_overflow_panics() {
+    let num1 = SciNote::parse_from_str("5x10^32000").unwrap();
+    let num2 = SciNote::parse_from_str("5x10^1000").unwrap();
+
+    let _ = num1 * num2;
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide failed")]
+fn test_operator_division_by_zero_panics() {
+    let num1 = SciNote::parse_from_str("5x10^3").unwrap();
+    let zero = SciNote::parse_from_str("0x10^0").unwrap();
+
+    let _ = num1 / zero;
+}
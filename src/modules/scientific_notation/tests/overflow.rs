@@ -0,0 +1,121 @@
+use rust_decimal::Decimal;
+use crate::SciNote;
+
+#[test]
+fn test_checked_ok() {
+    let num1 = SciNote::parse_from_str("6x10^4").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^2").unwrap();
+
+    assert_eq!(num1.checked_add(&num2), Some(SciNote::parse_from_str("6.02x10^4").unwrap()));
+    assert_eq!(num1.checked_sub(&num2), Some(SciNote::parse_from_str("5.98x10^4").unwrap()));
+    assert_eq!(num1.checked_mul(&num2), Some(SciNote::parse_from_str("1.2x10^7").unwrap()));
+    assert_eq!(num1.checked_div(&num2), Some(SciNote::parse_from_str("3x10^2").unwrap()));
+}
+
+#[test]
+fn test_checked_overflow() {
+    let big = SciNote::parse_from_str("5x10^32767").unwrap();
+    let small = SciNote::parse_from_str("5x10^-32768").unwrap();
+
+    assert_eq!(big.checked_add(&big), None);
+    assert_eq!(big.checked_sub(&-big), None);
+    assert_eq!(big.checked_mul(&big), None);
+    assert_eq!(small.checked_mul(&small), None);
+    assert_eq!(big.checked_div(&small), None);
+}
+
+#[test]
+fn test_checked_div_by_zero() {
+    let num = SciNote::parse_from_str("5x10^3").unwrap();
+    let zero = SciNote::parse_from_str("0x10^0").unwrap();
+
+    assert_eq!(num.checked_div(&zero), None);
+}
+
+#[test]
+fn test_checked_extreme_exponents() {
+    // Exponent differences beyond the i16 range don't overflow while aligning
+    let big = SciNote::parse_from_str("1x10^32767").unwrap();
+    let small = SciNote::parse_from_str("1x10^-32768").unwrap();
+
+    assert_eq!(big.checked_add(&small), Some(big));
+    assert_eq!(small.checked_sub(&big), Some(-big));
+}
+
+#[test]
+fn test_saturating_ok() {
+    let num1 = SciNote::parse_from_str("6x10^4").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^2").unwrap();
+
+    assert_eq!(num1.saturating_add(&num2), SciNote::parse_from_str("6.02x10^4").unwrap());
+    assert_eq!(num1.saturating_sub(&num2), SciNote::parse_from_str("5.98x10^4").unwrap());
+    assert_eq!(num1.saturating_mul(&num2), SciNote::parse_from_str("1.2x10^7").unwrap());
+    assert_eq!(num1.saturating_div(&num2), SciNote::parse_from_str("3x10^2").unwrap());
+}
+
+#[test]
+fn test_saturating_overflow() {
+    let big = SciNote::parse_from_str("5x10^32767").unwrap();
+    let small = SciNote::parse_from_str("5x10^-32768").unwrap();
+
+    assert_eq!(big.saturating_add(&big), SciNote::MAX);
+    assert_eq!((-big).saturating_sub(&big), SciNote::MIN);
+    assert_eq!(big.saturating_mul(&-big), SciNote::MIN);
+    assert_eq!(big.saturating_div(&small), SciNote::MAX);
+}
+
+#[test]
+fn test_saturating_underflow() {
+    let small = SciNote::parse_from_str("5x10^-32768").unwrap();
+    let big = SciNote::parse_from_str("5x10^32767").unwrap();
+
+    let result = small.saturating_mul(&small);
+    assert!(result.coefficient.is_zero());
+
+    let result = small.saturating_div(&big);
+    assert!(result.coefficient.is_zero());
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide failed")]
+fn test_saturating_div_by_zero_panics() {
+    let num = SciNote::parse_from_str("5x10^3").unwrap();
+    let zero = SciNote::parse_from_str("0x10^0").unwrap();
+
+    let _ = num.saturating_div(&zero);
+}
+
+#[test]
+fn test_overflowing_ok() {
+    let num1 = SciNote::parse_from_str("6x10^4").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^2").unwrap();
+
+    assert_eq!(num1.overflowing_add(&num2), (SciNote::parse_from_str("6.02x10^4").unwrap(), false));
+    assert_eq!(num1.overflowing_sub(&num2), (SciNote::parse_from_str("5.98x10^4").unwrap(), false));
+    assert_eq!(num1.overflowing_mul(&num2), (SciNote::parse_from_str("1.2x10^7").unwrap(), false));
+    assert_eq!(num1.overflowing_div(&num2), (SciNote::parse_from_str("3x10^2").unwrap(), false));
+}
+
+#[test]
+fn test_overflowing_wraps_exponent() {
+    // 5e32767 * 5e32767 = 2.5e65535, wrapped around i16 is 2.5e-1
+    let big = SciNote::parse_from_str("5x10^32767").unwrap();
+    let (result, overflowed) = big.overflowing_mul(&big);
+
+    assert!(overflowed);
+    assert_eq!(result.coefficient, Decimal::new(25, 1));
+    assert_eq!(result.exponent, -1);
+
+    // 5e32767 + 5e32767 = 1e32768, wrapped around i16 is 1e-32768
+    let (result, overflowed) = big.overflowing_add(&big);
+
+    assert!(overflowed);
+    assert_eq!(result.coefficient, Decimal::from(1));
+    assert_eq!(result.exponent, i16::MIN);
+}
+
+#[test]
+fn test_bounds() {
+    assert_eq!(SciNote::MAX.coefficient, Decimal::from_i128_with_scale(9999999999999999999999999999, 27));
+    assert_eq!(SciNote::MIN, -SciNote::MAX);
+}
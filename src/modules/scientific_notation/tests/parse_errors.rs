@@ -0,0 +1,100 @@
+use crate::{ParsePart, SciError, SciNote, SciParseError};
+
+fn parse_error(input: &str) -> SciParseError {
+    match SciNote::parse_from_str(input) {
+        Err(SciError::ParseError(error)) => error,
+        other => panic!("'{}' should fail with a parse error, returned {:?}", input, other)
+    }
+}
+
+fn assert_parse_error(input: &str, part: ParsePart, position: usize, fragment: &str) {
+    let error = parse_error(input);
+
+    assert_eq!(error.part(), part, "part of '{}'", input);
+    assert_eq!(error.position(), position, "position of '{}'", input);
+    assert_eq!(error.fragment(), fragment, "fragment of '{}'", input);
+    assert_eq!(&input[error.span()], fragment, "span of '{}'", input);
+}
+
+#[test]
+fn coefficient_errors() {
+    assert_parse_error("2ax10^20", ParsePart::Coefficient, 1, "a");
+    assert_parse_error("-2.5.1e3", ParsePart::Coefficient, 4, ".");
+    assert_parse_error("  abc", ParsePart::Coefficient, 2, "a");
+    assert_parse_error("x10^5", ParsePart::Coefficient, 0, "");
+    assert_parse_error("  e5", ParsePart::Coefficient, 2, "");
+    assert_parse_error("-x10^5", ParsePart::Coefficient, 0, "-");
+    assert_parse_error("1_000x10^2", ParsePart::Coefficient, 1, "_");
+    assert_parse_error(" 6.02_2e23", ParsePart::Coefficient, 5, "_");
+    assert_parse_error("1234567890123456789012345678901234567890x10^0", ParsePart::Coefficient, 0, "1234567890123456789012345678901234567890");
+}
+
+#[test]
+fn separator_errors() {
+    assert_parse_error("5x10", ParsePart::Separator, 4, "");
+    assert_parse_error("5x10 ^3", ParsePart::Separator, 4, " ^3");
+    assert_parse_error("5", ParsePart::Separator, 1, "");
+    assert_parse_error(" 1.5 ", ParsePart::Separator, 4, "");
+}
+
+#[test]
+fn exponent_errors() {
+    assert_parse_error("2x10^23.4", ParsePart::Exponent, 7, ".");
+    assert_parse_error("2x10^", ParsePart::Exponent, 5, "");
+    assert_parse_error("2x10^x10^23", ParsePart::Exponent, 5, "x");
+    assert_parse_error("6.022e2x", ParsePart::Exponent, 7, "x");
+    assert_parse_error("6.022·10²a", ParsePart::Exponent, 11, "a");
+    assert_parse_error("6.022 × 10²⁻", ParsePart::Exponent, 11, "²⁻");
+}
+
+#[test]
+fn exponent_out_of_range_errors() {
+    //  Exponents out of the i16 range, and the ones normalized out of it, point to the exponent
+    assert_parse_error("1x10^99999", ParsePart::Exponent, 5, "99999");
+    assert_parse_error(" -2.5e-40000", ParsePart::Exponent, 6, "-40000");
+    assert_parse_error("15.321x10^32767", ParsePart::Exponent, 10, "32767");
+    assert_parse_error("0.01x10^-32767", ParsePart::Exponent, 8, "-32767");
+    assert_parse_error("1·10⁹⁹⁹⁹⁹", ParsePart::Exponent, 5, "⁹⁹⁹⁹⁹");
+    assert_parse_error("7E99999999999999999999999999999999999999999", ParsePart::Exponent, 2, "99999999999999999999999999999999999999999");
+
+    assert_eq!(parse_error("1x10^99999").to_string(), "invalid exponent at byte 5: exponent out of range");
+}
+
+#[test]
+fn parse_error_display() {
+    let error = SciNote::parse_from_str("2x10^23.4").unwrap_err();
+
+    let message = error.to_string();
+    assert_eq!(message, "parse error: invalid exponent at byte 7: unexpected character '.'");
+
+    //  The message only holds the reason, the part and position are written once
+    let messages = [
+        ("6.02z2x10^3", "invalid coefficient at byte 4: unexpected character 'z'"),
+        ("-x10^5", "invalid coefficient at byte 0: missing digits"),
+        ("1234567890123456789012345678901234567890e0", "invalid coefficient at byte 0: too many digits for a Decimal"),
+        ("6.022·10²a", "invalid exponent at byte 11: unexpected character 'a'"),
+        ("6e+", "invalid exponent at byte 2: missing digits"),
+        ("6.022 × 10²⁻", "invalid exponent at byte 11: invalid digit found in string")
+    ];
+    for (input, expected) in messages {
+        assert_eq!(parse_error(input).to_string(), expected, "input: {}", input);
+    }
+}
+
+#[test]
+fn sci_error_display() {
+    assert_eq!(SciError::OperationError(Some("Division by zero".to_string())).to_string(), "operation error: Division by zero");
+    assert_eq!(SciError::ExponentError(None).to_string(), "exponent error");
+    assert_eq!(SciError::Unexpected("oops".to_string()).to_string(), "unexpected error: oops");
+}
+
+#[test]
+fn sci_error_as_std_error() {
+    fn parse_boxed(input: &str) -> Result<SciNote, Box<dyn std::error::Error>> {
+        Ok(input.parse::<SciNote>()?)
+    }
+
+    assert!(parse_boxed("3x10^8").is_ok());
+    let error = parse_boxed("3x10^8.5").unwrap_err();
+    assert!(error.to_string().contains("exponent"));
+}
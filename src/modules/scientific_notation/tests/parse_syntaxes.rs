@@ -0,0 +1,82 @@
+use rust_decimal::Decimal;
+use crate::{ParsePart, SciError, SciNote};
+
+fn assert_parsed(input: &str, coefficient: Decimal, exponent: i16) {
+    let sci_notation = SciNote::parse_from_str(input)
+        .unwrap_or_else(|error| panic!("failed to parse '{}': {:?}", input, error));
+
+    assert_eq!(sci_notation.coefficient, coefficient, "coefficient of '{}'", input);
+    assert_eq!(sci_notation.exponent, exponent, "exponent of '{}'", input);
+}
+
+#[test]
+fn parse_e_notation() {
+    assert_parsed("6.022e23", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022E23", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022E+23", Decimal::new(6022, 3), 23);
+    assert_parsed("-6.022e-23", Decimal::new(-6022, 3), -23);
+    assert_parsed("602.2e21", Decimal::new(6022, 3), 23);
+}
+
+#[test]
+fn parse_fortran_notation() {
+    assert_parsed("1.0D+05", Decimal::new(10, 1), 5);
+    assert_parsed("1.0d-05", Decimal::new(10, 1), -5);
+    assert_parsed("-2.5D3", Decimal::new(-25, 1), 3);
+}
+
+#[test]
+fn parse_multiplication_signs() {
+    assert_parsed("6.022x10^23", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022*10^23", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022×10^23", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022·10^23", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022 × 10^-23", Decimal::new(6022, 3), -23);
+}
+
+#[test]
+fn parse_superscript_exponents() {
+    assert_parsed("6.022·10²³", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022×10²³", Decimal::new(6022, 3), 23);
+    assert_parsed("6.022 × 10⁻²³", Decimal::new(6022, 3), -23);
+    assert_parsed("1.5x10⁺¹⁰", Decimal::new(15, 1), 10);
+    assert_parsed("9x10¹²³⁴⁵", Decimal::from(9), 12345);
+    assert_parsed("9x10⁻⁶⁷⁸⁹", Decimal::from(9), -6789);
+    assert_parsed("9x10⁰", Decimal::from(9), 0);
+}
+
+#[test]
+fn parse_surrounding_whitespace() {
+    assert_parsed("  3x10^8\n", Decimal::from(3), 8);
+    assert_parsed("\t3e8 ", Decimal::from(3), 8);
+}
+
+#[test]
+fn parse_exponent_normalized_back_into_range() {
+    // The raw exponent doesn't fit in an i16, but the normalized one does
+    assert_parsed("0.01x10^32768", Decimal::from(1), 32766);
+}
+
+#[test]
+fn parse_errors_are_parse_errors() {
+    let malformed = [
+        "", "x10^5", "e5", "5x10", "5x10^", "5e", "5x10^2.5", "5e2.5", "5x10^²", "5·10²a", "5x10^x10^2",
+        "5.0.1e3", "abc", "5", "5 x 10 ^ 3",
+    ];
+
+    for input in malformed {
+        let result = SciNote::parse_from_str(input);
+        assert!(matches!(result, Err(SciError::ParseError(_))), "'{}' returned {:?}", input, result);
+    }
+}
+
+#[test]
+fn parse_exponent_out_of_range() {
+    for input in ["5x10^32768", "5e-32769", "5e99999999999", "5x10^-99999999999"] {
+        let result = SciNote::parse_from_str(input);
+        assert!(
+            matches!(&result, Err(SciError::ParseError(error)) if error.part() == ParsePart::Exponent),
+            "'{}' returned {:?}", input, result
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use rust_decimal::Decimal;
 use crate::modules::scientific_notation::sci_not::SciNote;
+use crate::{ParsePart, SciError};
 
 // ---- POSITIVE BIG NUMBERS ----
 #[test]
@@ -59,7 +60,7 @@ fn positive_big_parse_and_display_back() {
     let input = "315.2x10^14";
     let sci_notation = SciNote::parse_from_str(input).unwrap();
 
-    assert_eq!(sci_notation.to_string(), input.to_string());
+    assert_eq!(sci_notation.to_string(), "3.15x10^16".to_string());
 }
 
 #[test]
@@ -78,19 +79,71 @@ fn positive_big_from_builder_into_string() {
     let sci_notation = SciNote::build()
         .coefficient(Decimal::new(6278964, 5))
         .exponent(20i16)
+        .finish()
+        .unwrap()
         .display_decimals(3);
 
-    assert_eq!(sci_notation.to_string(), "62.790x10^20".to_string());
+    assert_eq!(sci_notation.to_string(), "6.279x10^21".to_string());
 }
 
 #[test]
 fn positive_big_parse_max_exponent(){
     let max_exponent = i16::MAX.to_string();
-    let input = format!("15.321x10^{}", max_exponent);
+    let input = format!("1.5321x10^{}", max_exponent);
     let sci_notation = SciNote::parse_from_str(&input);
 
     assert!(sci_notation.is_ok());
     let result = sci_notation.unwrap();
-    assert_eq!(result.coefficient, Decimal::new(15321, 3));
+    assert_eq!(result.coefficient, Decimal::new(15321, 4));
     assert_eq!(result.exponent, i16::MAX);
 }
+
+#[test]
+fn positive_big_parse_max_exponent_normalized_out_of_range(){
+    let max_exponent = i16::MAX.to_string();
+    let input = format!("15.321x10^{}", max_exponent);
+    let sci_notation = SciNote::parse_from_str(&input);
+
+    assert!(matches!(sci_notation, Err(SciError::ParseError(error)) if error.part() == ParsePart::Exponent));
+}
+
+#[test]
+fn positive_big_parse_normalizes_coefficient() {
+    let input = "0.0005x10^10";
+    let sci_notation = SciNote::parse_from_str(input).unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::from(5));
+    assert_eq!(sci_notation.exponent, 6i16);
+}
+
+#[test]
+fn positive_big_builder_exponent_before_coefficient() {
+    let sci_notation = SciNote::build()
+        .exponent(20i16)
+        .coefficient(Decimal::new(6278964, 5))
+        .finish()
+        .unwrap();
+
+    assert_eq!(sci_notation.coefficient, Decimal::new(6278964, 6));
+    assert_eq!(sci_notation.exponent, 21i16);
+}
+
+#[test]
+fn positive_big_builder_with_error_exponent_out_of_range() {
+    let result = SciNote::build()
+        .coefficient(Decimal::from(10))
+        .exponent(i16::MAX)
+        .finish();
+
+    assert!(matches!(result, Err(SciError::ExponentError(_))));
+}
+
+#[test]
+fn positive_big_normalize_is_idempotent() {
+    let sci_notation = SciNote::parse_from_str("4.2x10^3").unwrap();
+
+    let normalized = sci_notation.normalize().unwrap();
+
+    assert_eq!(normalized.coefficient, sci_notation.coefficient);
+    assert_eq!(normalized.exponent, sci_notation.exponent);
+}
@@ -59,7 +59,7 @@ fn positive_small_parse_and_display_back() {
     let input = "315.2x10^-14";
     let sci_notation = SciNote::parse_from_str(input).unwrap();
 
-    assert_eq!(sci_notation.to_string(), input.to_string());
+    assert_eq!(sci_notation.to_string(), "3.15x10^-12".to_string());
 }
 
 #[test]
@@ -78,9 +78,11 @@ fn positive_small_from_builder_into_string() {
     let sci_notation = SciNote::build()
         .coefficient(Decimal::new(6278964, 5))
         .exponent(-20i16)
+        .finish()
+        .unwrap()
         .display_decimals(3);
 
-    assert_eq!(sci_notation.to_string(), "62.790x10^-20".to_string());
+    assert_eq!(sci_notation.to_string(), "6.279x10^-19".to_string());
 }
 
 #[test]
@@ -91,7 +93,7 @@ fn positive_small_parse_min_exponent(){
 
     assert!(sci_notation.is_ok());
     let result = sci_notation.unwrap();
-    assert_eq!(result.coefficient, Decimal::new(15321, 3));
-    assert_eq!(result.exponent, i16::MIN);
+    assert_eq!(result.coefficient, Decimal::new(15321, 4));
+    assert_eq!(result.exponent, i16::MIN + 1);
 }
 
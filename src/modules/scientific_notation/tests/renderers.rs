@@ -0,0 +1,60 @@
+use crate::{Notation, RoundingMode, SciNote};
+
+#[test]
+fn test_to_unicode() {
+    let value = SciNote::parse_from_str("6.022x10^23").unwrap().display_decimals(3);
+    assert_eq!(value.to_unicode(), "6.022 × 10²³");
+
+    let value = SciNote::parse_from_str("-1.602176634x10^-19").unwrap();
+    assert_eq!(value.to_unicode(), "-1.60 × 10⁻¹⁹");
+
+    //  The output can be parsed back
+    assert_eq!(SciNote::parse_from_str("6.022 × 10²³").unwrap().display_decimals(3).to_unicode(), "6.022 × 10²³");
+}
+
+#[test]
+fn test_to_latex() {
+    let value = SciNote::parse_from_str("6.022x10^23").unwrap().display_decimals(3);
+    assert_eq!(value.to_latex(), "6.022 \\times 10^{23}");
+
+    let value = SciNote::parse_from_str("-1.602176634x10^-19").unwrap();
+    assert_eq!(value.to_latex(), "-1.60 \\times 10^{-19}");
+}
+
+#[test]
+fn test_to_siunitx() {
+    let value = SciNote::parse_from_str("6.022x10^23").unwrap().display_decimals(3);
+    assert_eq!(value.to_siunitx(), "\\num{6.022e23}");
+
+    let value = SciNote::parse_from_str("-1.602176634x10^-19").unwrap();
+    assert_eq!(value.to_siunitx(), "\\num{-1.60e-19}");
+}
+
+#[test]
+fn test_to_mathml() {
+    let value = SciNote::parse_from_str("6.022x10^23").unwrap().display_decimals(3);
+    assert_eq!(
+        value.to_mathml(),
+        "<math><mn>6.022</mn><mo>×</mo><msup><mn>10</mn><mn>23</mn></msup></math>"
+    );
+
+    let value = SciNote::parse_from_str("-1.602176634x10^-19").unwrap().display_decimals(1);
+    assert_eq!(
+        value.to_mathml(),
+        "<math><mo>-</mo><mn>1.6</mn><mo>×</mo><msup><mn>10</mn><mrow><mo>-</mo><mn>19</mn></mrow></msup></math>"
+    );
+}
+
+#[test]
+fn test_renderers_display_settings() {
+    let value = SciNote::parse_from_str("9.8765x10^-5").unwrap();
+
+    assert_eq!(value.significant_figures(3).to_unicode(), "9.88 × 10⁻⁵");
+    assert_eq!(value.rounding(RoundingMode::TowardZero).to_latex(), "9.87 \\times 10^{-5}");
+    assert_eq!(value.display_decimals(0).to_siunitx(), "\\num{1e-4}");
+    assert_eq!(value.notation(Notation::Engineering).display_decimals(1).to_unicode(), "98.8 × 10⁻⁶");
+    assert_eq!(
+        value.significant_figures(2).to_mathml(),
+        "<math><mn>9.9</mn><mo>×</mo><msup><mn>10</mn><mrow><mo>-</mo><mn>5</mn></mrow></msup></math>"
+    );
+}
@@ -0,0 +1,89 @@
+use rust_decimal::Decimal;
+use crate::{RoundingMode, SciError, SciNote};
+
+#[test]
+fn test_round_sig_figs_modes() {
+    let value = SciNote::parse_from_str("2.345x10^4").unwrap();
+    let negative = SciNote::parse_from_str("-2.345x10^4").unwrap();
+
+    let cases = [
+        (RoundingMode::HalfEven, "2.34", "-2.34"),
+        (RoundingMode::HalfAwayFromZero, "2.35", "-2.35"),
+        (RoundingMode::HalfTowardZero, "2.34", "-2.34"),
+        (RoundingMode::AwayFromZero, "2.35", "-2.35"),
+        (RoundingMode::TowardZero, "2.34", "-2.34"),
+        (RoundingMode::Ceiling, "2.35", "-2.34"),
+        (RoundingMode::Floor, "2.34", "-2.35")
+    ];
+
+    for (mode, expected, expected_negative) in cases {
+        let rounded = value.round_sig_figs(3, mode).unwrap();
+        assert_eq!(rounded.coefficient, expected.parse::<Decimal>().unwrap(), "mode: {:?}", mode);
+        assert_eq!(rounded.exponent, 4);
+
+        let rounded = negative.round_sig_figs(3, mode).unwrap();
+        assert_eq!(rounded.coefficient, expected_negative.parse::<Decimal>().unwrap(), "mode: {:?}", mode);
+        assert_eq!(rounded.exponent, 4);
+    }
+}
+
+#[test]
+fn test_round_rolls_over() {
+    let value = SciNote::parse_from_str("9.996x10^2").unwrap();
+
+    let rounded = value.round_sig_figs(3, RoundingMode::HalfEven).unwrap();
+    assert_eq!(rounded.coefficient, Decimal::new(100, 2));
+    assert_eq!(rounded.exponent, 3);
+    assert_eq!(rounded.to_string(), "1.00x10^3");
+
+    let rounded = SciNote::parse_from_str("9.99x10^2").unwrap().round_decimals(1, RoundingMode::AwayFromZero).unwrap();
+    assert_eq!(rounded.coefficient, Decimal::new(10, 1));
+    assert_eq!(rounded.exponent, 3);
+
+    let rounded = SciNote::parse_from_str("-9.5x10^-3").unwrap().round_decimals(0, RoundingMode::HalfEven).unwrap();
+    assert_eq!(rounded, SciNote::parse_from_str("-1x10^-2").unwrap());
+}
+
+#[test]
+fn test_round_keeps_exact_values() {
+    let value = SciNote::parse_from_str("1.5x10^7").unwrap();
+    assert_eq!(value.round_sig_figs(10, RoundingMode::Floor).unwrap().coefficient, Decimal::new(15, 1));
+    assert_eq!(SciNote::zero().round_decimals(3, RoundingMode::Ceiling).unwrap(), SciNote::zero());
+}
+
+#[test]
+fn test_round_errors() {
+    let value = SciNote::parse_from_str("1.5x10^7").unwrap();
+    assert!(matches!(value.round_sig_figs(0, RoundingMode::HalfEven), Err(SciError::PrecisionError(_))));
+    assert!(matches!(value.round_sig_figs(29, RoundingMode::HalfEven), Err(SciError::PrecisionError(_))));
+
+    //  Rolling over the largest exponent can't be represented
+    assert!(matches!(SciNote::MAX.round_sig_figs(5, RoundingMode::HalfEven), Err(SciError::ExponentError(_))));
+    assert_eq!(SciNote::MAX.round_sig_figs(5, RoundingMode::TowardZero).unwrap().coefficient, Decimal::new(99999, 4));
+}
+
+#[test]
+fn test_display_rounding() {
+    let value = SciNote::parse_from_str("2.345x10^4").unwrap();
+
+    //  Ties are rounded towards zero by default
+    assert_eq!(value.to_string(), "2.34x10^4");
+    assert_eq!(value.rounding(RoundingMode::HalfAwayFromZero).to_string(), "2.35x10^4");
+    assert_eq!(value.rounding(RoundingMode::HalfEven).display_decimals(1).to_string(), "2.3x10^4");
+    assert_eq!(value.rounding(RoundingMode::Ceiling).significant_figures(2).to_string(), "2.4x10^4");
+
+    //  Displaying rolls over as well, without changing the value
+    let value = SciNote::parse_from_str("9.999x10^2").unwrap().rounding(RoundingMode::HalfEven);
+    assert_eq!(value.to_string(), "1.00x10^3");
+    assert_eq!(value.exponent, 2);
+    assert_eq!(SciNote::MAX.to_string(), "1.00x10^32768");
+}
+
+#[test]
+fn test_display_rounding_kept_by_operations() {
+    let first = SciNote::parse_from_str("1.125x10^0").unwrap().rounding(RoundingMode::HalfAwayFromZero);
+    let second = SciNote::parse_from_str("2x10^0").unwrap();
+
+    assert_eq!(second.mul(&first).unwrap().to_string(), "2.25x10^0");
+    assert_eq!(first.div(&second).unwrap().to_string(), "5.63x10^-1");
+}
@@ -56,4 +56,48 @@ fn shift_left_3() {
 
     assert_eq!(output, Decimal::from(3));
     assert_eq!(shift, -1);
-}
\ No newline at end of file
+}
+#[test]
+fn shift_right_with_decimals() {
+    let input = Decimal::new(21345, 1); // 2134.5
+    let (output, shift) = SciNote::scale_to_one_integer_digit(input).unwrap();
+
+    assert_eq!(output, Decimal::new(21345, 4));
+    assert_eq!(shift, 3);
+}
+#[test]
+fn shift_right_negative() {
+    let input = Decimal::from(-3000);
+    let (output, shift) = SciNote::scale_to_one_integer_digit(input).unwrap();
+
+    assert_eq!(output, Decimal::from(-3));
+    assert_eq!(shift, 3);
+}
+#[test]
+fn shift_left_negative() {
+    let input = Decimal::new(-12, 3); // -0.012
+    let (output, shift) = SciNote::scale_to_one_integer_digit(input).unwrap();
+
+    assert_eq!(output, Decimal::new(-12, 1));
+    assert_eq!(shift, -2);
+}
+#[test]
+fn shift_widest_mantissa() {
+    let (output, shift) = SciNote::scale_to_one_integer_digit(Decimal::MAX).unwrap();
+    assert_eq!(output, Decimal::from_i128_with_scale(Decimal::MAX.mantissa(), 28));
+    assert_eq!(shift, 28);
+
+    let (output, shift) = SciNote::scale_to_one_integer_digit(Decimal::new(-1, 28)).unwrap();
+    assert_eq!(output, Decimal::from(-1));
+    assert_eq!(shift, -28);
+}
+#[test]
+fn shift_keeps_trailing_zeros() {
+    let (output, shift) = SciNote::scale_to_one_integer_digit(Decimal::new(1230, 7)).unwrap();
+    assert_eq!(output.to_string(), "1.230");
+    assert_eq!(shift, -4);
+
+    let (output, shift) = SciNote::scale_to_one_integer_digit(Decimal::new(500, 0)).unwrap();
+    assert_eq!(output.to_string(), "5.00");
+    assert_eq!(shift, 2);
+}
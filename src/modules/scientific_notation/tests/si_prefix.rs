@@ -0,0 +1,108 @@
+use rust_decimal::Decimal;
+use crate::{Notation, ParsePart, SciError, SciNote};
+
+#[test]
+fn test_to_si_prefix() {
+    let cases = [
+        ("4.7x10^3", "4.7", "k"),
+        ("2.2x10^-7", "220", "n"),
+        ("1.5x10^9", "1.5", "G"),
+        ("3.3x10^-6", "3.3", "µ"),
+        ("4.7x10^1", "47", ""),
+        ("1x10^30", "1", "Q"),
+        ("5x10^28", "50", "R"),
+        ("1x10^-27", "1", "r"),
+        ("9.9x10^-29", "99", "q")
+    ];
+
+    for (input, coefficient, prefix) in cases {
+        let value = SciNote::parse_from_str(input).unwrap();
+        assert_eq!(value.to_si_prefix(), Some((coefficient.parse::<Decimal>().unwrap(), prefix)), "input: {}", input);
+    }
+
+    assert_eq!(SciNote::parse_from_str("1x10^33").unwrap().to_si_prefix(), None);
+    assert_eq!(SciNote::parse_from_str("9.9x10^-31").unwrap().to_si_prefix(), None);
+}
+
+#[test]
+fn test_si_prefix_display() {
+    let display = |input: &str| SciNote::parse_from_str(input).unwrap().notation(Notation::SiPrefix).to_string();
+
+    assert_eq!(display("4.7x10^3"), "4.7 k");
+    assert_eq!(display("2.2x10^-7"), "220 n");
+    assert_eq!(display("1.5x10^9"), "1.5 G");
+    assert_eq!(display("-3.3x10^-6"), "-3.3 µ");
+    assert_eq!(display("4.7x10^1"), "47");
+    assert_eq!(display("2.5x10^31"), "25 Q");
+    assert_eq!(display("0"), "0");
+
+    //  Out of the prefixes range, fall back to scientific notation
+    assert_eq!(display("2.5x10^33"), "2.5x10^33");
+    assert_eq!(display("-1.25x10^-31"), "-1.25x10^-31");
+}
+
+#[test]
+fn test_si_prefix_display_settings() {
+    let value = SciNote::parse_from_str("4.7149x10^3").unwrap().notation(Notation::SiPrefix);
+
+    assert_eq!(format!("{:.1}", value), "4.7 k");
+    assert_eq!(format!("{:>8.0}|", value), "     5 k|");
+    assert_eq!(value.significant_figures(3).to_string(), "4.71 k");
+
+    //  Rounding can carry into the next prefix
+    let value = SciNote::parse_from_str("9.99996x10^5").unwrap().notation(Notation::SiPrefix);
+    assert_eq!(value.to_string(), "1.00 M");
+}
+
+#[test]
+fn test_parse_si() {
+    let cases = [
+        ("4.7k", "4.7x10^3"),
+        ("220n", "2.2x10^-7"),
+        ("3.3 µ", "3.3x10^-6"),
+        ("3.3μ", "3.3x10^-6"),
+        ("3.3u", "3.3x10^-6"),
+        ("1.5 G", "1.5x10^9"),
+        ("-10m", "-1x10^-2"),
+        ("  47  ", "4.7x10^1"),
+        ("2R", "2x10^27"),
+        ("2Q", "2x10^30"),
+        ("2r", "2x10^-27"),
+        ("2q", "2x10^-30"),
+        ("5da", "5x10^1"),
+        ("5c", "5x10^-2"),
+        ("0k", "0")
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(SciNote::parse_si(input).unwrap(), SciNote::parse_from_str(expected).unwrap(), "input: {}", input);
+    }
+}
+
+#[test]
+fn test_parse_si_round_trip() {
+    for input in ["4.7 k", "220 n", "-1.5 G", "3.3 µ", "999 q", "1 Q"] {
+        let value = SciNote::parse_si(input).unwrap().notation(Notation::SiPrefix);
+        assert_eq!(value.to_string(), input);
+    }
+}
+
+#[test]
+fn test_parse_si_errors() {
+    let Err(SciError::ParseError(error)) = SciNote::parse_si("4.7 K") else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(error.part(), ParsePart::Prefix);
+    assert_eq!(error.fragment(), "K");
+    assert_eq!(error.span(), 4..5);
+
+    let Err(SciError::ParseError(error)) = SciNote::parse_si("4.7.1k") else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(error.part(), ParsePart::Coefficient);
+
+    let Err(SciError::ParseError(error)) = SciNote::parse_si("k") else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(error.part(), ParsePart::Coefficient);
+}
@@ -0,0 +1,88 @@
+use rust_decimal::Decimal;
+use crate::SciNote;
+
+#[test]
+fn test_significant_figures_keep_trailing_zeros() {
+    let value = SciNote::build().coefficient(Decimal::from(5)).exponent(3).finish().unwrap().significant_figures(3);
+    assert_eq!(value.to_string(), "5.00x10^3");
+
+    let value = SciNote::parse_from_str("5.00x10^3").unwrap().significant_figures(3);
+    assert_eq!(value.to_string(), "5.00x10^3");
+
+    let value = SciNote::parse_from_str("-2.5x10^-4").unwrap().significant_figures(5);
+    assert_eq!(value.to_string(), "-2.5000x10^-4");
+
+    let value = SciNote::zero().significant_figures(3);
+    assert_eq!(value.to_string(), "0.00x10^0");
+}
+
+#[test]
+fn test_significant_figures_rounding() {
+    let value = SciNote::parse_from_str("6.02214076x10^23").unwrap().significant_figures(4);
+    assert_eq!(value.to_string(), "6.022x10^23");
+
+    let value = SciNote::parse_from_str("6.02214076x10^23").unwrap().significant_figures(1);
+    assert_eq!(value.to_string(), "6x10^23");
+
+    //  Rounding into a new integer digit adapts the exponent
+    let value = SciNote::parse_from_str("9.996x10^2").unwrap().significant_figures(3);
+    assert_eq!(value.to_string(), "1.00x10^3");
+}
+
+#[test]
+fn test_significant_figures_replace_display_decimals() {
+    let value = SciNote::parse_from_str("1.23456x10^2").unwrap();
+
+    assert_eq!(value.display_decimals(1).significant_figures(4).to_string(), "1.235x10^2");
+    assert_eq!(value.significant_figures(4).display_decimals(1).to_string(), "1.2x10^2");
+
+    //  The figures are limited to the precision of the coefficient
+    assert_eq!(value.significant_figures(0).to_string(), "1x10^2");
+    assert_eq!(value.significant_figures(40).to_string(), "1.234560000000000000000000000x10^2");
+}
+
+#[test]
+fn test_significant_figures_kept_by_operations() {
+    let first = SciNote::parse_from_str("2x10^3").unwrap().significant_figures(3);
+    let second = SciNote::parse_from_str("4x10^1").unwrap();
+
+    assert_eq!(first.mul(&second).unwrap().to_string(), "8.00x10^4");
+    assert_eq!(second.add(&first).unwrap().to_string(), "2.04x10^3");
+    assert_eq!(first.saturating_div(&second).to_string(), "5.00x10^1");
+}
+
+#[test]
+fn test_count_significant_figures() {
+    let cases = [
+        ("5.00x10^3", 3),
+        ("5x10^3", 1),
+        ("-6.02214076e23", 9),
+        ("0.0045x10^3", 2),
+        ("0.004050x10^3", 4),
+        ("500x10^3", 1),
+        ("500.x10^3", 3),
+        ("1.0D+05", 2),
+        ("1.20×10⁻⁵", 3),
+        ("0", 1),
+        ("0.000x10^2", 3)
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(SciNote::count_significant_figures(input).unwrap(), expected, "input: {}", input);
+    }
+}
+
+#[test]
+fn test_count_significant_figures_invalid_input() {
+    assert!(SciNote::count_significant_figures("5.0.0x10^3").is_err());
+    assert!(SciNote::count_significant_figures("5.00").is_err());
+}
+
+#[test]
+fn test_counted_significant_figures_display() {
+    let input = "1.230x10^-7";
+    let figures = SciNote::count_significant_figures(input).unwrap();
+    let value = SciNote::parse_from_str(input).unwrap().significant_figures(figures);
+
+    assert_eq!(value.to_string(), input);
+}
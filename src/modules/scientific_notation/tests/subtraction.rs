@@ -0,0 +1,86 @@
+use rust_decimal::Decimal;
+use crate::SciNote;
+
+#[test]
+fn test_subtraction_ok() {
+    let num1 = SciNote::parse_from_str("5x10^12").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^10").unwrap();
+
+    let result = num1.sub(&num2).unwrap();
+
+    assert_eq!(result.to_string(), "4.98x10^12".to_string());
+}
+
+#[test]
+fn test_subtraction_ok_2() {
+    let num1 = SciNote::parse_from_str("5x10^12").unwrap();
+    let num2 = SciNote::parse_from_str("2x10^12").unwrap();
+
+    let result = num1.sub(&num2).unwrap();
+
+    assert_eq!(result.to_string(), "3x10^12".to_string());
+}
+
+#[test]
+fn test_subtraction_normalizes_down() {
+    // 1.5e3 - 1.4e3 = 100 -> one integer digit
+    let num1 = SciNote::parse_from_str("1.5x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("1.4x10^3").unwrap();
+
+    let result = num1.sub(&num2).unwrap();
+
+    assert_eq!(result.coefficient, Decimal::from(1));
+    assert_eq!(result.exponent, 2);
+}
+
+#[test]
+fn test_subtraction_crossing_zero() {
+    // 2e3 - 5e3 = -3e3
+    let num1 = SciNote::parse_from_str("2x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("5x10^3").unwrap();
+    let result = num1.sub(&num2).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("-3x10^3").unwrap());
+
+    // 2e2 - 5e3 = -4.8e3
+    let num3 = SciNote::parse_from_str("2x10^2").unwrap();
+    let result = num3.sub(&num2).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("-4.8x10^3").unwrap());
+
+    // -2e3 - (-5e3) = 3e3
+    let num4 = SciNote::parse_from_str("-2x10^3").unwrap();
+    let num5 = SciNote::parse_from_str("-5x10^3").unwrap();
+    let result = num4.sub(&num5).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("3x10^3").unwrap());
+}
+
+#[test]
+fn test_subtraction_equal_operands() {
+    let num1 = SciNote::parse_from_str("6.022x10^23").unwrap();
+
+    let result = num1.sub(&num1).unwrap();
+
+    assert!(result.coefficient.is_zero());
+}
+
+#[test]
+fn test_subtraction_small_numbers() {
+    // 2.5e-2 - 3.5e-3 = 2.15e-2
+    let num1 = SciNote::parse_from_str("2.5x10^-2").unwrap();
+    let num2 = SciNote::parse_from_str("3.5x10^-3").unwrap();
+    let result = num1.sub(&num2).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("2.15x10^-2").unwrap());
+
+    // 3.5e-3 - 2.5e-2 = -2.15e-2
+    let result = num2.sub(&num1).unwrap();
+    assert_eq!(result, SciNote::parse_from_str("-2.15x10^-2").unwrap());
+}
+
+#[test]
+fn test_subtraction_operator() {
+    let num1 = SciNote::parse_from_str("1x10^3").unwrap();
+    let num2 = SciNote::parse_from_str("1x10^2").unwrap();
+
+    let result = num1 - num2;
+
+    assert_eq!(result, SciNote::parse_from_str("9x10^2").unwrap());
+}
@@ -67,7 +67,7 @@ fn test_addition_different_exponents() {
     let num1 = SciNote::parse_from_str("1x10^3").unwrap(); // 1.0e+3
     let num2 = SciNote::parse_from_str("1x10^2").unwrap(); // 1.0e+2
     let result = num1.add(&num2).unwrap();
-    assert_eq!(result, SciNote::parse_from_str("1x10^3").unwrap()); // Expected: 1.1e+3 (1000 + 100)
+    assert_eq!(result, SciNote::parse_from_str("1.1x10^3").unwrap()); // Expected: 1.1e+3 (1000 + 100)
 
     let num3 = SciNote::parse_from_str("2.5x10^-2").unwrap(); // 2.5e-2
     let num4 = SciNote::parse_from_str("3.5x10^-3").unwrap(); // 3.5e-3
@@ -132,5 +132,5 @@ fn test_precision_handling() {
     let num3 = SciNote::parse_from_str("1.23456789x10^3").unwrap(); // 1.23456789e+3
     let num4 = SciNote::parse_from_str("9.87654321x10^2").unwrap(); // 9.87654321e+2
     let result = num3.add(&num4).unwrap();
-    assert_eq!(result, SciNote::parse_from_str("1.33222221x10^3").unwrap()); // Expected: 1.33222221e+3
+    assert_eq!(result, SciNote::parse_from_str("2.222222211x10^3").unwrap()); // Expected: 2.222222211e+3
 }
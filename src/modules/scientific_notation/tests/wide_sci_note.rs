@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+use rust_decimal::Decimal;
+use crate::{Notation, ParsePart, RoundingMode, SciError, SciNote, WideSciNote};
+
+fn parse(input: &str) -> WideSciNote {
+    WideSciNote::parse_from_str(input).unwrap()
+}
+
+#[test]
+fn test_parse_wide_exponents() {
+    let value = parse("1x10^-100000");
+    assert_eq!(value.coefficient, Decimal::ONE);
+    assert_eq!(value.exponent, -100000);
+
+    let value = parse("-25e1000000");
+    assert_eq!(value.coefficient, Decimal::new(-25, 1));
+    assert_eq!(value.exponent, 1000001);
+
+    //  Every syntax of SciNote is accepted
+    for input in ["6.022x10^23", "6.022*10^23", "6.022 × 10²³", "6.022E+23", "0.6022D24", "602.2e21"] {
+        assert_eq!(parse(input), parse("6.022x10^23"), "input: {}", input);
+    }
+    assert!(parse("0").is_zero());
+    assert!(parse("0x10^9000000000000000000").is_zero());
+    assert!("12e3".parse::<WideSciNote>().is_ok());
+    assert!(WideSciNote::try_from("12e3").is_ok());
+
+    assert!(matches!(WideSciNote::parse_from_str("3.1.4x10^2"), Err(SciError::ParseError(_))));
+    for input in ["99.9x10^9223372036854775807", "1x10^9223372036854775808", "1e-99999999999999999999999999999999999999999"] {
+        let Err(SciError::ParseError(error)) = WideSciNote::parse_from_str(input) else {
+            panic!("expected a parse error for '{}'", input)
+        };
+        assert_eq!(error.part(), ParsePart::Exponent);
+        assert_eq!(&input[error.span()], input.rsplit(['^', 'e']).next().unwrap());
+    }
+}
+
+#[test]
+fn test_new() {
+    let value = WideSciNote::new(Decimal::new(-4700, 0), -50000).unwrap();
+    assert_eq!(value, parse("-4.7x10^-49997"));
+
+    assert!(WideSciNote::new(Decimal::ZERO, i64::MAX).unwrap().is_zero());
+    assert!(matches!(WideSciNote::new(Decimal::TEN, i64::MAX), Err(SciError::ExponentError(_))));
+}
+
+#[test]
+fn test_arithmetic() {
+    //  Likelihood products go far below the i16 range
+    let mut likelihood = parse("1x10^0");
+    for _ in 0..10000 {
+        likelihood *= parse("2x10^-10");
+    }
+    assert_eq!(likelihood.exponent, -96990);
+    assert_eq!(likelihood.round_sig_figs(5, RoundingMode::HalfEven).unwrap(), parse("1.9951x10^-96990"));
+
+    assert_eq!(parse("3x10^600000") * parse("4x10^500000"), parse("1.2x10^1100001"));
+    assert_eq!(parse("1x10^-100000") / parse("4x10^100000"), parse("2.5x10^-200001"));
+    assert_eq!(parse("9x10^70000") + parse("2x10^70000"), parse("1.1x10^70001"));
+    assert_eq!(parse("1x10^70000") - parse("1x10^69999"), parse("9x10^69999"));
+    assert!((parse("-4.2x10^-70000") + parse("4.2x10^-70000")).is_zero());
+    assert_eq!(-parse("4.2x10^70000"), parse("-4.2x10^70000"));
+
+    //  Far apart operands are absorbed, whatever the distance
+    let (sum, inexact) = parse("1x10^100000").add_with_inexact(&parse("1x10^-100000")).unwrap();
+    assert_eq!(sum, parse("1x10^100000"));
+    assert!(inexact);
+    let (sum, inexact) = parse("1x10^100000").sub_with_inexact(&parse("5x10^99990")).unwrap();
+    assert_eq!(sum, parse("9.999999995x10^99999"));
+    assert!(!inexact);
+
+    assert!(matches!(WideSciNote::MAX.mul(&parse("10x10^0")), Err(SciError::ExponentError(_))));
+    assert!(matches!(WideSciNote::MAX.add(&WideSciNote::MAX), Err(SciError::ExponentError(_))));
+    assert!(matches!(parse("1x10^0").div(&WideSciNote::zero()), Err(SciError::OperationError(_))));
+    assert_eq!(WideSciNote::MAX * parse("1x10^-9223372036854775807"), parse("9.999999999999999999999999999x10^0"));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(parse("1x10^-100000").to_string(), "1x10^-100000");
+    assert_eq!(parse("6.02214076x10^1000000").display_decimals(3).to_string(), "6.022x10^1000000");
+    assert_eq!(parse("5x10^-50000").significant_figures(3).to_string(), "5.00x10^-50000");
+    assert_eq!(parse("-9.999x10^99999").to_string(), "-1.00x10^100000");
+    assert_eq!(format!("{:>+18}|", parse("3.14159x10^40000")), "    +3.14x10^40000|");
+    assert_eq!(parse("1.25x10^40000").display_decimals(1).rounding(RoundingMode::Ceiling).to_string(), "1.3x10^40000");
+    assert_eq!(WideSciNote::MAX.to_string(), "1.00x10^9223372036854775808");
+
+    assert_eq!(format!("{:e}", parse("-1.602176634x10^-190000")), "-1.602176634e-190000");
+    assert_eq!(format!("{:.2E}", parse("6.02214076x10^230000")), "6.02E230000");
+    assert_eq!(format!("{:.30}", parse("6.02214076x10^230000")), format!("6.02214076{}x10^230000", "0".repeat(22)));
+    assert_eq!(WideSciNote::zero().to_string(), "0x10^0");
+}
+
+#[test]
+fn test_comparison() {
+    assert_eq!(parse("1.50x10^50000"), parse("15x10^49999"));
+    assert!(parse("1x10^-100000") > WideSciNote::zero());
+    assert!(parse("-1x10^-100000") < WideSciNote::zero());
+    assert!(parse("1x10^40000") > parse("9x10^39999"));
+    assert!(parse("-1x10^40000") < parse("-9x10^39999"));
+    assert!(WideSciNote::MIN < WideSciNote::MAX);
+
+    let set = ["1.50x10^50000", "15x10^49999", "1.5e50000", "0", "0x10^90000"]
+        .map(parse)
+        .into_iter()
+        .collect::<HashSet<WideSciNote>>();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_sci_note_conversions() {
+    let value = SciNote::parse_from_str("-6.02214076x10^23").unwrap().display_decimals(4);
+    let wide = WideSciNote::from(value);
+    assert_eq!(wide, parse("-6.02214076x10^23"));
+    assert_eq!(wide.to_string(), "-6.0221x10^23");
+    assert_eq!(SciNote::try_from(wide).unwrap(), value);
+
+    assert_eq!(SciNote::try_from(WideSciNote::from(SciNote::MAX)).unwrap(), SciNote::MAX);
+    assert_eq!(SciNote::try_from(WideSciNote::from(SciNote::MIN)).unwrap(), SciNote::MIN);
+    assert!(SciNote::try_from(WideSciNote::zero()).unwrap().is_zero());
+
+    //  The exponent has to fit an i16
+    assert!(matches!(SciNote::try_from(parse("1x10^32768")), Err(SciError::ExponentError(_))));
+    assert!(matches!(SciNote::try_from(parse("1x10^-32769")), Err(SciError::ExponentError(_))));
+    assert_eq!(SciNote::try_from(parse("1x10^-32768")).unwrap(), SciNote::parse_from_str("1x10^-32768").unwrap());
+}
+
+#[test]
+fn test_notations() {
+    //  The point is placed for the whole exponent, not for the part that fits in a SciNote
+    assert_eq!(parse("4.7x10^100000").notation(Notation::Engineering).to_string(), "47x10^99999");
+    assert_eq!(parse("4.7x10^-100001").notation(Notation::Engineering).to_string(), "47x10^-100002");
+    assert_eq!(format!("{:.1}", parse("4.7x10^100000").notation(Notation::Engineering)), "47.0x10^99999");
+    assert_eq!(format!("{:e}", parse("6.02214076x10^1000001").notation(Notation::Engineering)), "602.214076e999999");
+
+    //  A rounding that carries into a new integer digit moves to the next multiple of three
+    assert_eq!(parse("9.999996x10^1000001").notation(Notation::Engineering).to_string(), "1.00x10^1000002");
+    assert_eq!(parse("9.999996x10^1000002").notation(Notation::Engineering).to_string(), "10.00x10^1000002");
+
+    assert_eq!(parse("1x10^5").notation(Notation::SiPrefix).to_string(), "100 k");
+    assert_eq!(parse("1.2346x10^-7").notation(Notation::SiPrefix).display_decimals(1).to_string(), "123.5 n");
+    assert_eq!(parse("2.5x10^100000").notation(Notation::SiPrefix).to_string(), "2.5x10^100000");
+
+    //  The notation of a SciNote is kept
+    let value = WideSciNote::from(SciNote::parse_from_str("4.7x10^4").unwrap().notation(Notation::SiPrefix));
+    assert_eq!(value.to_string(), "47 k");
+    assert_eq!((value * parse("1x10^3")).to_string(), "47 M");
+}
+
+#[test]
+fn test_renderers() {
+    let value = parse("-1.6x10^-190000");
+    assert_eq!(value.to_unicode(), "-1.6 × 10⁻¹⁹⁰⁰⁰⁰");
+    assert_eq!(value.to_latex(), "-1.6 \\times 10^{-190000}");
+    assert_eq!(value.to_siunitx(), "\\num{-1.6e-190000}");
+    assert_eq!(
+        value.to_mathml(),
+        "<math><mo>-</mo><mn>1.6</mn><mo>×</mo><msup><mn>10</mn><mrow><mo>-</mo><mn>190000</mn></mrow></msup></math>"
+    );
+}
+
+#[test]
+fn test_string_conversions() {
+    assert_eq!(WideSciNote::try_from(String::from("1x10^-100000")).unwrap(), parse("1x10^-100000"));
+    assert!(matches!(WideSciNote::try_from(String::from("1x10^")), Err(SciError::ParseError(_))));
+    assert_eq!(WideSciNote::zero().display_decimals, None);
+}
+
+#[test]
+fn test_overflow_aware_arithmetic() {
+    let huge = parse("5x10^9223372036854775807");
+    let tiny = parse("5x10^-9223372036854775808");
+
+    assert_eq!(huge.checked_add(&huge), None);
+    assert_eq!(huge.checked_sub(&huge), Some(WideSciNote::zero()));
+    assert_eq!(huge.checked_mul(&parse("2x10^0")), None);
+    assert_eq!(tiny.checked_div(&parse("1x10^1")), None);
+    assert_eq!(huge.checked_div(&WideSciNote::zero()), None);
+
+    assert_eq!(huge.saturating_add(&huge), WideSciNote::MAX);
+    assert_eq!((-huge).saturating_sub(&huge), WideSciNote::MIN);
+    assert_eq!(huge.saturating_mul(&parse("-3x10^5")), WideSciNote::MIN);
+    assert!(tiny.saturating_div(&parse("1x10^1")).is_zero());
+    assert_eq!(parse("2x10^100000").saturating_mul(&parse("3x10^100000")), parse("6x10^200000"));
+
+    let (sum, overflowed) = huge.overflowing_add(&huge);
+    assert!(overflowed);
+    assert_eq!(sum.exponent, i64::MIN);
+    assert_eq!(sum.coefficient, Decimal::ONE);
+
+    let (quotient, overflowed) = tiny.overflowing_div(&parse("5x10^1"));
+    assert!(overflowed);
+    assert_eq!(quotient.exponent, i64::MAX);
+
+    let (difference, overflowed) = parse("1x10^90000").overflowing_sub(&parse("1x10^-90000"));
+    assert!(!overflowed);
+    assert_eq!(difference, parse("1x10^90000"));
+}
+
+#[test]
+fn test_display_at_the_minimum_exponent() {
+    let value = parse("1e-9223372036854775808");
+    assert_eq!(value.to_string(), "1x10^-9223372036854775808");
+    assert_eq!(format!("{:e}", value), "1e-9223372036854775808");
+    assert_eq!(format!("{:E}", value), "1E-9223372036854775808");
+    assert_eq!(value.notation(Notation::Engineering).to_string(), "10x10^-9223372036854775809");
+    assert_eq!(value.to_unicode(), "1 × 10⁻⁹²²³³⁷²⁰³⁶⁸⁵⁴⁷⁷⁵⁸⁰⁸");
+    assert_eq!(value.to_latex(), "1 \\times 10^{-9223372036854775808}");
+    assert_eq!(value.to_siunitx(), "\\num{1e-9223372036854775808}");
+    assert_eq!(
+        value.to_mathml(),
+        "<math><mn>1</mn><mo>×</mo><msup><mn>10</mn><mrow><mo>-</mo><mn>9223372036854775808</mn></mrow></msup></math>"
+    );
+
+    let value = parse("-2.5e-9223372036854775807");
+    assert_eq!(value.to_string(), "-2.5x10^-9223372036854775807");
+    assert_eq!(format!("{:e}", value), "-2.5e-9223372036854775807");
+    assert_eq!(format!("{:E}", value), "-2.5E-9223372036854775807");
+    assert_eq!(value.notation(Notation::Engineering).to_string(), "-250x10^-9223372036854775809");
+    assert_eq!(value.notation(Notation::SiPrefix).to_string(), "-2.5x10^-9223372036854775807");
+    assert_eq!(value.to_unicode(), "-2.5 × 10⁻⁹²²³³⁷²⁰³⁶⁸⁵⁴⁷⁷⁵⁸⁰⁷");
+    assert_eq!(value.to_latex(), "-2.5 \\times 10^{-9223372036854775807}");
+    assert_eq!(value.to_siunitx(), "\\num{-2.5e-9223372036854775807}");
+    assert_eq!(
+        value.to_mathml(),
+        "<math><mo>-</mo><mn>2.5</mn><mo>×</mo><msup><mn>10</mn><mrow><mo>-</mo><mn>9223372036854775807</mn></mrow></msup></math>"
+    );
+}
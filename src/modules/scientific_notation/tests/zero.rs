@@ -0,0 +1,98 @@
+use rust_decimal::Decimal;
+use crate::{SciError, SciNote};
+
+fn assert_canonical_zero(value: &SciNote) {
+    assert!(value.is_zero());
+    assert_eq!(value.coefficient, Decimal::ZERO);
+    assert!(!value.coefficient.is_sign_negative());
+    assert_eq!(value.exponent, 0);
+}
+
+#[test]
+fn test_parse_zero() {
+    for input in ["0", "0.0", "-0", "0x10^5", "0x10^-12", "-0.000x10^3", "0.00x10^0"] {
+        let value = SciNote::parse_from_str(input).unwrap();
+        assert_canonical_zero(&value);
+        assert_eq!(value.to_string(), "0x10^0");
+    }
+}
+
+#[test]
+fn test_parse_plain_non_zero_is_rejected() {
+    assert!(SciNote::parse_from_str("5").is_err());
+}
+
+#[test]
+fn test_parse_zero_with_exponent_out_of_range() {
+    for input in ["0x10^99999999999", "-0.0e-99999999999", "0x10^99999999999999999999999"] {
+        assert_canonical_zero(&SciNote::parse_from_str(input).unwrap());
+    }
+    assert!(matches!(SciNote::parse_from_str("1x10^99999999999"), Err(SciError::ParseError(_))));
+    assert!(matches!(SciNote::parse_from_str("0x10^9a"), Err(SciError::ParseError(_))));
+}
+
+#[test]
+fn test_zero_constructor() {
+    assert_canonical_zero(&SciNote::zero());
+    assert!(!SciNote::parse_from_str("1x10^-30").unwrap().is_zero());
+
+    //  It's the same zero as the parsed one, adding it keeps the display of the other operand
+    let value = SciNote::parse_from_str("1.234x10^3").unwrap();
+    assert_eq!((SciNote::zero() + value).to_string(), "1.23x10^3");
+    assert_eq!((SciNote::zero() + value).to_string(), (SciNote::parse_from_str("0").unwrap() + value).to_string());
+    assert_eq!(SciNote::zero().display_decimals, SciNote::parse_from_str("0").unwrap().display_decimals);
+}
+
+#[test]
+fn test_builder_zero() {
+    let value = SciNote::build().coefficient(Decimal::ZERO).exponent(20).finish().unwrap();
+    assert_canonical_zero(&value);
+
+    // The exponent given to the builder is kept for a later coefficient
+    let value = SciNote::build().coefficient(Decimal::ZERO).exponent(20).coefficient(Decimal::from(5)).finish().unwrap();
+    assert_eq!(value, SciNote::parse_from_str("5x10^20").unwrap());
+}
+
+#[test]
+fn test_arithmetic_cancelling_out() {
+    let num1 = SciNote::parse_from_str("4.5x10^-7").unwrap();
+    let num2 = SciNote::parse_from_str("-4.5x10^-7").unwrap();
+
+    assert_canonical_zero(&num1.add(&num2).unwrap());
+    assert_canonical_zero(&num1.sub(&num1).unwrap());
+    assert_canonical_zero(&(num2 - num2));
+}
+
+#[test]
+fn test_arithmetic_with_zero_operands() {
+    let zero = SciNote::parse_from_str("0").unwrap();
+    let num = SciNote::parse_from_str("-3.2x10^15").unwrap();
+
+    assert_eq!(zero.add(&num).unwrap(), num);
+    assert_eq!(num.add(&zero).unwrap(), num);
+    assert_eq!(num.sub(&zero).unwrap(), num);
+    assert_eq!(zero.sub(&num).unwrap(), -num);
+    assert_canonical_zero(&zero.mul(&num).unwrap());
+    assert_canonical_zero(&num.mul(&zero).unwrap());
+    assert_canonical_zero(&zero.div(&num).unwrap());
+    assert!(matches!(num.div(&zero), Err(SciError::OperationError(_))));
+    assert!(matches!(zero.div(&zero), Err(SciError::OperationError(_))));
+}
+
+#[test]
+fn test_zero_with_extreme_operands() {
+    // The product of zero and a huge number is zero, the exponents are never added
+    let zero = SciNote::parse_from_str("0").unwrap();
+    let big = SciNote::parse_from_str("9x10^32767").unwrap();
+
+    assert_canonical_zero(&zero.mul(&big).unwrap());
+    assert_canonical_zero(&zero.div(&big).unwrap());
+    assert_eq!(zero.overflowing_mul(&big), (SciNote::zero(), false));
+}
+
+#[test]
+fn test_negated_zero() {
+    let zero = SciNote::zero();
+
+    assert_canonical_zero(&-zero);
+}
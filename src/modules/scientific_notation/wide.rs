@@ -0,0 +1,300 @@
+use rust_decimal::Decimal;
+use crate::modules::scientific_notation::error::{SciError, SciResult};
+use crate::modules::scientific_notation::format::FormatOptions;
+use crate::modules::scientific_notation::parse;
+use crate::modules::scientific_notation::rounding::RoundingMode;
+use crate::modules::scientific_notation::sci_not::SciNote;
+
+/// Exponent difference past which the smaller operand of a sum is always rounded away: shifting a
+/// coefficient this many places drops more digits than a Decimal mantissa has
+const ABSORBING_EXPONENT_DIFF: i16 = 60;
+
+#[derive(Debug, Clone, Copy)]
+/// Scientific notation with an i64 exponent, for values far out of the i16 range of [SciNote],
+/// like likelihood products, `1x10^-100000`, or state space sizes, `1x10^1000000`. It has the
+/// parsing, display and arithmetic APIs of [SciNote], with the same 28 digits coefficient.
+///
+/// The arithmetic is computed by [SciNote] on the coefficients and the exponents are combined apart,
+/// a result out of the i64 range returns a `SciError::ExponentError`
+///
+/// ## Conversions:
+/// Every [SciNote] converts into a WideSciNote, keeping its display settings and notation.
+/// Converting back fails with an `ExponentError` if the exponent is out of the i16 range
+pub struct WideSciNote {
+    pub(crate) coefficient: Decimal,
+    pub(crate) exponent: i64,
+    pub(crate) display_decimals: Option<usize>,
+    pub(crate) format: FormatOptions
+}
+
+impl WideSciNote {
+    /// Largest representable value: 9.999999999999999999999999999x10^9223372036854775807
+    pub const MAX: WideSciNote = WideSciNote {
+        coefficient: SciNote::MAX.coefficient,
+        exponent: i64::MAX,
+        display_decimals: None,
+        format: FormatOptions { significant_figures: None, rounding: None, notation: None }
+    };
+
+    /// Smallest representable value: -9.999999999999999999999999999x10^9223372036854775807
+    pub const MIN: WideSciNote = WideSciNote {
+        coefficient: SciNote::MIN.coefficient,
+        exponent: i64::MAX,
+        display_decimals: None,
+        format: FormatOptions { significant_figures: None, rounding: None, notation: None }
+    };
+
+    pub fn zero() -> Self {
+        Self {
+            coefficient: Decimal::ZERO,
+            exponent: 0,
+            display_decimals: None,
+            format: FormatOptions::default()
+        }
+    }
+
+    /// Create a value from a coefficient and its exponent. The coefficient is normalized to one
+    /// integer digit and the exponent is adapted, failing if it goes out of the i64 range
+    pub fn new(coefficient: Decimal, exponent: i64) -> SciResult<Self> {
+        let (coefficient, shift) = SciNote::scale_to_one_integer_digit(coefficient)?;
+        Self::from_unbounded(
+            coefficient,
+            i128::from(exponent) + i128::from(shift),
+            None,
+            FormatOptions::default()
+        )
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficient.is_zero()
+    }
+
+    /// Parse a number in any syntax accepted by [SciNote::parse_from_str], with an exponent in
+    /// the i64 range, so `1x10^-100000` and `2.5e1000000` are accepted
+    pub fn parse_from_str(input: &str) -> SciResult<Self> {
+        parse::parse_notation_with(input, parse::parse_coefficient, Decimal::is_zero, |coefficient, exponent| {
+            Self::new(coefficient, parse::exponent_in_range(exponent)?)
+        })
+    }
+
+    pub fn add(&self, input2: &Self) -> SciResult<Self> {
+        self.add_with_inexact(input2).map(|(result, _)| result)
+    }
+
+    /// Add like [WideSciNote::add], also returning whether the result was rounded: true when the
+    /// operands are too far apart for the sum to keep every digit, like `1x10^100000 + 1x10^-100000`
+    pub fn add_with_inexact(&self, input2: &Self) -> SciResult<(Self, bool)> {
+        let (coefficient, exponent, inexact) = self.add_unbounded_inexact(input2)?;
+        let result = Self::from_unbounded(
+            coefficient,
+            exponent,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )?;
+
+        Ok((result, inexact))
+    }
+
+    /// Subtract like [WideSciNote::sub], also returning whether the result was rounded
+    pub fn sub_with_inexact(&self, input2: &Self) -> SciResult<(Self, bool)> {
+        self.add_with_inexact(&-input2)
+    }
+
+    pub fn mul(&self, input2: &Self) -> SciResult<Self> {
+        let (coefficient, exponent) = self.mul_unbounded(input2)?;
+        Self::from_unbounded(
+            coefficient,
+            exponent,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
+    }
+
+    /// Divide by `input2`, keeping up to [SciNote::MAX_DIVISION_DIGITS] significant digits and
+    /// rounding half to even. See [WideSciNote::div_with_precision] to select the precision and rounding
+    pub fn div(&self, input2: &Self) -> SciResult<Self> {
+        self.div_with_precision(input2, SciNote::MAX_DIVISION_DIGITS, SciNote::DIVISION_ROUNDING)
+    }
+
+    /// Divide by `input2`, rounding the result coefficient to `max_significant_digits` with the
+    /// `rounding` mode. Returns the same errors as [SciNote::div_with_precision]
+    pub fn div_with_precision(
+        &self,
+        input2: &Self,
+        max_significant_digits: u32,
+        rounding: RoundingMode
+    ) -> SciResult<Self> {
+        let (coefficient, exponent) = self.div_unbounded(input2, max_significant_digits, rounding)?;
+        Self::from_unbounded(
+            coefficient,
+            exponent,
+            self.display_decimals.or(input2.display_decimals),
+            self.format.or(input2.format)
+        )
+    }
+
+    /// Round the coefficient to `figures` significant figures like [SciNote::round_sig_figs]
+    pub fn round_sig_figs(&self, figures: u32, mode: RoundingMode) -> SciResult<Self> {
+        let rounded = self.relative(0).round_sig_figs(figures, mode)?;
+        Self::from_unbounded(
+            rounded.coefficient,
+            i128::from(self.exponent) + i128::from(rounded.exponent),
+            self.display_decimals,
+            self.format
+        )
+    }
+
+    /// Round the coefficient to `decimals` decimal places like [SciNote::round_decimals]
+    pub fn round_decimals(&self, decimals: u32, mode: RoundingMode) -> SciResult<Self> {
+        let rounded = self.relative(0).round_decimals(decimals, mode)?;
+        Self::from_unbounded(
+            rounded.coefficient,
+            i128::from(self.exponent) + i128::from(rounded.exponent),
+            self.display_decimals,
+            self.format
+        )
+    }
+
+    /// Sum both numbers, returning the scaled coefficient and its exponent
+    pub(super) fn add_unbounded(&self, input2: &Self) -> SciResult<(Decimal, i128)> {
+        self.add_unbounded_inexact(input2).map(|(coefficient, exponent, _)| (coefficient, exponent))
+    }
+
+    /// Sum both numbers, returning the scaled coefficient, its exponent and whether it was rounded
+    pub(super) fn add_unbounded_inexact(&self, input2: &Self) -> SciResult<(Decimal, i128, bool)> {
+        //  Adding zero doesn't change the other operand
+        if self.is_zero() || input2.is_zero() {
+            let kept = if self.is_zero() { input2 } else { self };
+            return Ok((kept.coefficient, i128::from(kept.exponent), false))
+        }
+
+        //  The sum is computed relative to the bigger exponent. Past the absorbing difference, every
+        // difference rounds the smaller operand away in the same way, so it's limited to fit an i16
+        let (bigger, smaller) = if self.exponent >= input2.exponent {
+            (self, input2)
+        } else {
+            (input2, self)
+        };
+        let exponent_diff = bigger.exponent.abs_diff(smaller.exponent)
+            .min(ABSORBING_EXPONENT_DIFF as u64) as i16;
+
+        let (coefficient, exponent, inexact) = bigger
+            .relative(0)
+            .add_unbounded_inexact(&smaller.relative(-exponent_diff))?;
+        Ok((coefficient, i128::from(bigger.exponent) + i128::from(exponent), inexact))
+    }
+
+    /// Multiply both numbers, returning the scaled coefficient and its exponent
+    pub(super) fn mul_unbounded(&self, input2: &Self) -> SciResult<(Decimal, i128)> {
+        let (coefficient, exponent) = self.relative(0).mul_unbounded(&input2.relative(0))?;
+        Ok((coefficient, i128::from(self.exponent) + i128::from(input2.exponent) + i128::from(exponent)))
+    }
+
+    /// Divide both numbers, returning the scaled coefficient and its exponent
+    pub(super) fn div_unbounded(
+        &self,
+        input2: &Self,
+        max_significant_digits: u32,
+        rounding: RoundingMode
+    ) -> SciResult<(Decimal, i128)> {
+        let (coefficient, exponent) = self
+            .relative(0)
+            .div_unbounded(&input2.relative(0), max_significant_digits, rounding)?;
+        Ok((coefficient, i128::from(self.exponent) - i128::from(input2.exponent) + i128::from(exponent)))
+    }
+
+    /// The same coefficient and settings with the `exponent` instead of the wide one, so the
+    /// [SciNote] arithmetic and display can be reused. The wide exponent is added back afterwards
+    fn relative(&self, exponent: i16) -> SciNote {
+        SciNote {
+            coefficient: self.coefficient,
+            exponent,
+            display_decimals: self.display_decimals,
+            format: self.format
+        }
+    }
+
+    /// Build a new instance from a scaled coefficient and an exponent, failing if the exponent is
+    /// out of the i64 range. Exponents are combined in an i128, where they can't overflow
+    fn from_unbounded(
+        coefficient: Decimal,
+        exponent: i128,
+        display_decimals: Option<usize>,
+        format: FormatOptions
+    ) -> SciResult<Self> {
+        //  There's a single zero, 0x10^0, regardless of the exponent or sign it was computed with
+        if coefficient.is_zero() {
+            return Ok(Self {
+                coefficient: Decimal::ZERO,
+                exponent: 0,
+                display_decimals,
+                format
+            })
+        }
+
+        let exponent = i64::try_from(exponent)
+            .map_err(|_| SciError::ExponentError(Some(format!("Exponent {} out of range", exponent))))?;
+
+        Ok(Self {
+            coefficient,
+            exponent,
+            display_decimals,
+            format
+        })
+    }
+
+    /// Coefficient and exponent as they're displayed. The exponent is wider than an i64 because
+    /// a rounding that carries into a new integer digit may take it past the maximum
+    pub(super) fn display_parts(&self, precision: Option<usize>) -> (Decimal, i128) {
+        let (base, relative) = self.display_relative();
+        let (coefficient, exponent) = relative.display_parts(precision);
+        (coefficient, base + i128::from(exponent))
+    }
+
+    /// Coefficient and exponent of the E-notation, with the whole coefficient unless it's limited
+    pub(super) fn exp_parts(&self, precision: Option<usize>) -> (Decimal, i128) {
+        let (base, relative) = self.display_relative();
+        let (coefficient, exponent) = relative.exp_parts(precision);
+        (coefficient, base + i128::from(exponent))
+    }
+
+    /// Split the exponent into a multiple of three, and the value relative to it, so the [SciNote]
+    /// display places the point of the engineering notations like it would for the whole exponent.
+    /// The multiple of three below the minimum exponent is out of the i64 range, so it's an i128
+    fn display_relative(&self) -> (i128, SciNote) {
+        let remainder = self.exponent.rem_euclid(3);
+        (i128::from(self.exponent) - i128::from(remainder), self.relative(remainder as i16))
+    }
+}
+
+impl Default for WideSciNote {
+    fn default() -> Self {
+        Self::from(SciNote::default())
+    }
+}
+
+impl From<SciNote> for WideSciNote {
+    fn from(value: SciNote) -> Self {
+        Self {
+            coefficient: value.coefficient,
+            exponent: i64::from(value.exponent),
+            display_decimals: value.display_decimals,
+            format: value.format
+        }
+    }
+}
+
+impl TryFrom<WideSciNote> for SciNote {
+    type Error = SciError;
+    fn try_from(value: WideSciNote) -> Result<Self, Self::Error> {
+        let exponent = i16::try_from(value.exponent)
+            .map_err(|_| SciError::ExponentError(Some(format!("Exponent {} out of range", value.exponent))))?;
+
+        Ok(SciNote {
+            coefficient: value.coefficient,
+            exponent,
+            display_decimals: value.display_decimals,
+            format: value.format
+        })
+    }
+}